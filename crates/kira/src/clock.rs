@@ -162,7 +162,7 @@ pub use handle::*;
 pub use time::*;
 
 use std::sync::{
-	atomic::{AtomicBool, AtomicU64, Ordering},
+	atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
 	Arc,
 };
 
@@ -179,25 +179,57 @@ use self::clock_info::ClockInfoProvider;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ClockId(pub(crate) Key);
 
+/// Whether a clock is ticking, paused, or stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClockState {
+	/// The clock is not ticking, and its time is reset to 0.
+	///
+	/// Clocks are stopped when they're first created.
+	Stopped,
+	/// The clock is not ticking, but will continue from the same
+	/// time when it's started again.
+	Paused,
+	/// The clock is ticking.
+	Ticking,
+}
+
 pub(crate) struct ClockShared {
-	ticking: AtomicBool,
+	state: AtomicU8,
 	ticks: AtomicU64,
 	fractional_position: AtomicU64,
+	/// The ticks and fractional position combined into a single value,
+	/// so both can be read at once without tearing.
+	position: AtomicU64,
 	removed: AtomicBool,
 }
 
 impl ClockShared {
 	pub fn new() -> Self {
 		Self {
-			ticking: AtomicBool::new(false),
+			state: AtomicU8::new(ClockState::Stopped as u8),
 			ticks: AtomicU64::new(0),
 			fractional_position: AtomicU64::new(0.0f64.to_bits()),
+			position: AtomicU64::new(0.0f64.to_bits()),
 			removed: AtomicBool::new(false),
 		}
 	}
 
 	pub fn ticking(&self) -> bool {
-		self.ticking.load(Ordering::SeqCst)
+		self.state() == ClockState::Ticking
+	}
+
+	pub fn state(&self) -> ClockState {
+		match self.state.load(Ordering::SeqCst) {
+			0 => ClockState::Stopped,
+			1 => ClockState::Paused,
+			2 => ClockState::Ticking,
+			_ => panic!("Invalid clock state"),
+		}
+	}
+
+	fn set_state(&self, state: ClockState) {
+		self.state.store(state as u8, Ordering::SeqCst);
 	}
 
 	pub fn ticks(&self) -> u64 {
@@ -208,6 +240,10 @@ impl ClockShared {
 		f64::from_bits(self.fractional_position.load(Ordering::SeqCst))
 	}
 
+	pub fn position(&self) -> f64 {
+		f64::from_bits(self.position.load(Ordering::SeqCst))
+	}
+
 	pub fn is_marked_for_removal(&self) -> bool {
 		self.removed.load(Ordering::SeqCst)
 	}
@@ -254,18 +290,22 @@ impl Clock {
 
 	pub(crate) fn start(&mut self) {
 		self.ticking = true;
-		self.shared.ticking.store(true, Ordering::SeqCst);
+		self.shared.set_state(ClockState::Ticking);
 	}
 
 	pub(crate) fn pause(&mut self) {
+		// pausing a clock that hasn't been started doesn't do anything
+		if self.ticking || self.state != State::NotStarted {
+			self.shared.set_state(ClockState::Paused);
+		}
 		self.ticking = false;
-		self.shared.ticking.store(false, Ordering::SeqCst);
 	}
 
 	pub(crate) fn stop(&mut self) {
-		self.pause();
+		self.ticking = false;
 		self.state = State::NotStarted;
 		self.shared.ticks.store(0, Ordering::SeqCst);
+		self.shared.set_state(ClockState::Stopped);
 	}
 
 	pub(crate) fn on_start_processing(&mut self) {
@@ -280,6 +320,10 @@ impl Clock {
 		self.shared
 			.fractional_position
 			.store(fractional_position.to_bits(), Ordering::SeqCst);
+		self.shared.position.store(
+			(ticks as f64 + fractional_position).to_bits(),
+			Ordering::SeqCst,
+		);
 	}
 
 	/// Updates the [`Clock`].
@@ -6,7 +6,7 @@ use crate::{
 	tween::{Tween, Value},
 };
 
-use super::{ClockId, ClockShared, ClockSpeed, ClockTime};
+use super::{ClockId, ClockShared, ClockSpeed, ClockState, ClockTime};
 
 /// Controls a clock.
 ///
@@ -30,6 +30,16 @@ impl ClockHandle {
 		self.shared.ticking()
 	}
 
+	/// Returns whether the clock is ticking, paused, or stopped.
+	///
+	/// The state is updated once per processing block, when the audio
+	/// thread handles a call to [`start`](ClockHandle::start),
+	/// [`pause`](ClockHandle::pause), or [`stop`](ClockHandle::stop),
+	/// so it may lag behind those calls by up to one block.
+	pub fn state(&self) -> ClockState {
+		self.shared.state()
+	}
+
 	/// Returns the current time of the clock.
 	pub fn time(&self) -> ClockTime {
 		ClockTime {
@@ -45,6 +55,23 @@ impl ClockHandle {
 		self.shared.fractional_position()
 	}
 
+	/**
+	Returns the current position of the clock in ticks, including
+	the time between ticks.
+
+	For example, a position of `4.5` is halfway between the 4th
+	and 5th ticks. This is useful for animating things in time with
+	the clock. Unlike reading [`time`](ClockHandle::time) and
+	[`fractional_position`](ClockHandle::fractional_position) separately,
+	the whole and fractional parts are always from the same update.
+
+	Since the position is accumulated as the clock ticks, it stays
+	accurate when the speed of the clock changes.
+	*/
+	pub fn position(&self) -> f64 {
+		self.shared.position()
+	}
+
 	/// Sets the speed of the clock.
 	pub fn set_speed(
 		&self,
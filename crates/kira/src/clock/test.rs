@@ -10,7 +10,7 @@ use crate::{
 	StartTime,
 };
 
-use super::{Clock, ClockSpeed};
+use super::{Clock, ClockSpeed, ClockState};
 
 /// Tests that a `Clock` is stopped when it's first created.
 #[test]
@@ -270,3 +270,59 @@ fn fractional_position() {
 	clock.on_start_processing();
 	assert_eq!(shared.fractional_position(), 0.25);
 }
+
+/// Tests that a clock correctly reports its combined position.
+#[test]
+#[allow(clippy::float_cmp)]
+fn position() {
+	let mut clock = Clock::new(Value::Fixed(ClockSpeed::SecondsPerTick(1.0)));
+	let shared = clock.shared();
+	assert_eq!(shared.position(), 0.0);
+	clock.start();
+	clock.update(
+		0.5,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	clock.on_start_processing();
+	assert_eq!(shared.position(), 0.5);
+	clock.set_speed(
+		Value::Fixed(ClockSpeed::SecondsPerTick(0.5)),
+		Tween::default(),
+	);
+	clock.update(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	clock.on_start_processing();
+	assert_eq!(shared.position(), 2.5);
+	clock.stop();
+	clock.on_start_processing();
+	assert_eq!(shared.position(), 0.0);
+}
+
+/// Tests that a clock correctly reports whether it's ticking,
+/// paused, or stopped.
+#[test]
+fn state() {
+	let mut clock = Clock::new(Value::Fixed(ClockSpeed::SecondsPerTick(1.0)));
+	let shared = clock.shared();
+	assert_eq!(shared.state(), ClockState::Stopped);
+	// pausing a stopped clock should keep it stopped
+	clock.pause();
+	assert_eq!(shared.state(), ClockState::Stopped);
+	clock.start();
+	assert_eq!(shared.state(), ClockState::Ticking);
+	clock.update(
+		1.5,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	clock.pause();
+	assert_eq!(shared.state(), ClockState::Paused);
+	clock.start();
+	assert_eq!(shared.state(), ClockState::Ticking);
+	clock.stop();
+	assert_eq!(shared.state(), ClockState::Stopped);
+}
@@ -53,6 +53,18 @@ impl Frame {
 	}
 }
 
+impl From<f32> for Frame {
+	fn from(value: f32) -> Self {
+		Self::from_mono(value)
+	}
+}
+
+impl From<[f32; 2]> for Frame {
+	fn from([left, right]: [f32; 2]) -> Self {
+		Self::new(left, right)
+	}
+}
+
 impl Add for Frame {
 	type Output = Self;
 
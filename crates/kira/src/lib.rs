@@ -158,18 +158,23 @@ audio formats, like MP3 and WAV.
 - `serde` - adds `Serialize` and `Deserialize` implementations for the following types:
 	- [`Capacities`](crate::manager::Capacities)
 	- [`ClockSpeed`](crate::clock::ClockSpeed)
+	- [`ClockState`](crate::clock::ClockState)
 	- [`DistortionKind`](crate::track::effect::distortion::DistortionKind)
 	- [`Easing`](crate::tween::Easing)
 	- [`EndPosition`](crate::sound::EndPosition)
+	- [`Envelope`](crate::sound::static_sound::Envelope)
 	- [`EqFilterKind`](crate::track::effect::eq_filter::EqFilterKind)
 	- [`FilterMode`](crate::track::effect::filter::FilterMode)
 	- [`Frame`](crate::dsp::Frame)
 	- [`MainPlaybackState`](crate::manager::MainPlaybackState)
+	- [`Markers`](crate::sound::Markers)
 	- [`ModulatorMapping`](crate::tween::ModulatorMapping)
 	- [`PlaybackPosition`](crate::sound::PlaybackPosition)
 	- [`PlaybackRate`](crate::sound::PlaybackRate)
 	- [`PlaybackState`](crate::sound::PlaybackState)
 	- [`Region`](crate::sound::Region)
+	- [`Signal`](crate::sound::static_sound::Signal)
+	- [`SoundStats`](crate::sound::static_sound::SoundStats)
 	- [`Volume`](crate::Volume)
 	- [`Waveform`](crate::modulator::lfo::Waveform)
 - `assert_no_alloc` - uses the [`assert_no_alloc`](https://crates.io/crates/assert_no_alloc) crate
@@ -23,7 +23,7 @@ use crate::{
 	error::CommandError,
 	manager::command::ModulatorCommand,
 	modulator::{ModulatorBuilder, ModulatorId},
-	sound::SoundData,
+	sound::{SoundData, SoundId, SoundInfo},
 	spatial::scene::{SpatialScene, SpatialSceneHandle, SpatialSceneId, SpatialSceneSettings},
 	track::{SubTrackId, Track, TrackBuilder, TrackHandle, TrackId},
 	tween::{Tween, Value},
@@ -78,6 +78,9 @@ pub struct AudioManager<B: Backend = DefaultBackend> {
 	command_producer: CommandProducer,
 	resource_controllers: ResourceControllers,
 	unused_resource_consumers: UnusedResourceConsumers,
+	/// Information about the sounds that have been sent to the renderer
+	/// and haven't been removed yet, in the order they were played.
+	sound_info: Vec<(SoundId, SoundInfo)>,
 }
 
 impl<B: Backend> AudioManager<B> {
@@ -132,6 +135,7 @@ impl<B: Backend> AudioManager<B> {
 			command_producer: CommandProducer::new(command_producer),
 			resource_controllers,
 			unused_resource_consumers,
+			sound_info: vec![],
 		})
 	}
 
@@ -159,27 +163,56 @@ impl<B: Backend> AudioManager<B> {
 		&mut self,
 		sound_data: D,
 	) -> Result<D::Handle, PlaySoundError<D::Error>> {
-		while self
-			.unused_resource_consumers
-			.sound
-			.get_mut()
-			.expect("unused resource consumer mutex poisoned")
-			.pop()
-			.is_some()
-		{}
+		self.remove_unused_sounds();
 		let key = self
 			.resource_controllers
 			.sound_controller
 			.try_reserve()
 			.map_err(|_| PlaySoundError::SoundLimitReached)?;
+		let info = sound_data.info();
 		let (sound, handle) = sound_data
 			.into_sound()
 			.map_err(PlaySoundError::IntoSoundError)?;
 		self.command_producer
 			.push(Command::Sound(SoundCommand::Add(key, sound)))?;
+		self.sound_info.push((SoundId(key), info));
 		Ok(handle)
 	}
 
+	/**
+	Returns information about each sound that's currently loaded,
+	such as its duration and an estimate of how much memory it uses.
+
+	Each sound is listed with a [`SoundId`] that stays the same for as
+	long as the sound is loaded. Sounds are listed in the order they
+	were played.
+
+	The information is tracked on the gameplay thread, so this doesn't
+	need to communicate with the audio thread. Sounds stay in the list
+	until the audio thread has finished with them, so sounds that
+	finished very recently may still be included.
+
+	# Examples
+
+	Finding out how much memory playing sounds are using:
+
+	```no_run
+	use kira::manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let memory_usage: usize = manager
+		.playing_sounds()
+		.iter()
+		.map(|(_, info)| info.memory_usage)
+		.sum();
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn playing_sounds(&mut self) -> Vec<(SoundId, SoundInfo)> {
+		self.remove_unused_sounds();
+		self.sound_info.clone()
+	}
+
 	/// Creates a mixer sub-track.
 	pub fn add_sub_track(
 		&mut self,
@@ -408,6 +441,51 @@ impl<B: Backend> AudioManager<B> {
 		self.command_producer.push(Command::Resume(fade_out_tween))
 	}
 
+	/**
+	Fades out and pauses every sound that is currently playing.
+
+	Unlike [`pause`](AudioManager::pause), this pauses each sound
+	individually, so clocks and modulators keep running and new
+	sounds can still be played. Sounds that are already paused will
+	stay paused when [`resume_all`](AudioManager::resume_all) is called.
+
+	# Examples
+
+	```no_run
+	# use kira::{
+	# 	manager::{
+	# 		AudioManager, AudioManagerSettings,
+	# 		backend::DefaultBackend,
+	# 	},
+	# };
+	use kira::tween::Tween;
+	use std::time::Duration;
+
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	manager.pause_all(Tween {
+		duration: Duration::from_secs(1),
+		..Default::default()
+	})?;
+	// later...
+	manager.resume_all(Tween {
+		duration: Duration::from_secs(1),
+		..Default::default()
+	})?;
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn pause_all(&self, fade_out_tween: Tween) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Sound(SoundCommand::PauseAll(None, fade_out_tween)))
+	}
+
+	/// Resumes and fades in every sound that was paused by
+	/// [`pause_all`](AudioManager::pause_all).
+	pub fn resume_all(&self, fade_in_tween: Tween) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Sound(SoundCommand::ResumeAll(None, fade_in_tween)))
+	}
+
 	/**
 	Returns a handle to the main mixer track.
 
@@ -499,4 +577,22 @@ impl<B: Backend> AudioManager<B> {
 	pub fn backend_mut(&mut self) -> &mut B {
 		&mut self.backend
 	}
+
+	/// Drops sounds that the renderer is finished with and forgets
+	/// their information.
+	fn remove_unused_sounds(&mut self) {
+		let unused_sound_consumer = self
+			.unused_resource_consumers
+			.sound
+			.get_mut()
+			.expect("unused resource consumer mutex poisoned");
+		let mut removed_keys = HashSet::new();
+		while let Some((key, _)) = unused_sound_consumer.pop() {
+			removed_keys.insert(key);
+		}
+		if !removed_keys.is_empty() {
+			self.sound_info
+				.retain(|(id, _)| !removed_keys.contains(&id.0));
+		}
+	}
 }
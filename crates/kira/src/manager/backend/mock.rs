@@ -1,6 +1,6 @@
 //! Useful for testing and benchmarking.
 
-use std::sync::Mutex;
+use std::{sync::Mutex, time::Duration};
 
 use crate::dsp::Frame;
 
@@ -29,6 +29,22 @@ impl Default for MockBackendSettings {
 /// [`Renderer::on_start_processing`] and [`Renderer::process`].
 ///
 /// This is useful for testing and benchmarking.
+///
+/// # Determinism
+///
+/// The [`Renderer`] does not use any sources of randomness or timing
+/// information besides the sample rate. Sounds, mixer tracks, clocks,
+/// and modulators are always processed in the same order. As a result,
+/// sending the same commands in between the same calls to
+/// [`on_start_processing`](MockBackend::on_start_processing) and
+/// [`process`](MockBackend::process) produces bit-identical output
+/// every time, which makes the mock backend suitable for regression
+/// tests and replays.
+///
+/// This is only guaranteed for the same build of a program running on
+/// the same platform. Some effects use floating point functions like
+/// `sin` and `powf`, whose results can differ slightly between
+/// platforms and compilers.
 pub struct MockBackend {
 	sample_rate: u32,
 	state: State,
@@ -67,6 +83,68 @@ impl MockBackend {
 			panic!("backend is not initialized")
 		}
 	}
+
+	/**
+	Renders `duration` worth of audio as fast as possible and returns
+	the output.
+
+	[`on_start_processing`](MockBackend::on_start_processing) is called
+	before every `block_size` frames, the same way a real backend
+	would request audio in batches. Commands sent to the audio manager
+	before calling this function take effect at the start of the
+	render, so the output can be built up by alternating between
+	sending commands and rendering chunks of audio.
+
+	Because the length of the render is always given explicitly, this
+	is safe to use with looping sounds.
+
+	# Examples
+
+	Bouncing a sound to a buffer of frames:
+
+	```
+	use std::time::Duration;
+	use kira::{
+		manager::{
+			backend::mock::{MockBackend, MockBackendSettings},
+			AudioManager, AudioManagerSettings,
+		},
+		sound::static_sound::{StaticSoundData, StaticSoundSettings},
+	};
+
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+		backend_settings: MockBackendSettings { sample_rate: 48_000 },
+		..Default::default()
+	})
+	.unwrap();
+	manager
+		.play(StaticSoundData::from_samples(
+			48_000,
+			vec![0.5f32; 48_000],
+			StaticSoundSettings::new().loop_region(..),
+		))
+		.unwrap();
+	let output = manager.backend_mut().render(Duration::from_secs(2), 512);
+	assert_eq!(output.len(), 96_000);
+	```
+
+	# Panics
+
+	Panics if the backend hasn't been started or `block_size` is `0`.
+	*/
+	pub fn render(&mut self, duration: Duration, block_size: usize) -> Vec<Frame> {
+		assert!(block_size > 0, "block size must be greater than 0");
+		let num_frames = (duration.as_secs_f64() * self.sample_rate as f64).round() as usize;
+		let mut output = Vec::with_capacity(num_frames);
+		while output.len() < num_frames {
+			self.on_start_processing();
+			let block_length = block_size.min(num_frames - output.len());
+			for _ in 0..block_length {
+				output.push(self.process());
+			}
+		}
+		output
+	}
 }
 
 impl Backend for MockBackend {
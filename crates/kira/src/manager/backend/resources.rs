@@ -6,7 +6,7 @@ pub(crate) mod spatial_scenes;
 
 use std::sync::Mutex;
 
-use atomic_arena::Controller;
+use atomic_arena::{Controller, Key};
 use ringbuf::{HeapConsumer, HeapProducer, HeapRb};
 
 use crate::{
@@ -24,7 +24,7 @@ use self::{
 };
 
 pub(crate) struct UnusedResourceProducers {
-	pub sound: HeapProducer<Box<dyn Sound>>,
+	pub sound: HeapProducer<(Key, Box<dyn Sound>)>,
 	pub sub_track: HeapProducer<Track>,
 	pub clock: HeapProducer<Clock>,
 	pub spatial_scene: HeapProducer<SpatialScene>,
@@ -32,7 +32,7 @@ pub(crate) struct UnusedResourceProducers {
 }
 
 pub(crate) struct UnusedResourceConsumers {
-	pub sound: Mutex<HeapConsumer<Box<dyn Sound>>>,
+	pub sound: Mutex<HeapConsumer<(Key, Box<dyn Sound>)>>,
 	pub sub_track: Mutex<HeapConsumer<Track>>,
 	pub clock: Mutex<HeapConsumer<Clock>>,
 	pub spatial_scene: Mutex<HeapConsumer<SpatialScene>>,
@@ -1,20 +1,24 @@
-use atomic_arena::{Arena, Controller};
+use atomic_arena::{Arena, Controller, Key};
 use ringbuf::HeapProducer;
 
 use crate::{
 	clock::clock_info::ClockInfoProvider, manager::command::SoundCommand,
-	modulator::value_provider::ModulatorValueProvider, sound::Sound, OutputDestination,
+	modulator::value_provider::ModulatorValueProvider, sound::Sound, track::TrackId,
+	OutputDestination,
 };
 
 use super::{mixer::Mixer, spatial_scenes::SpatialScenes};
 
 pub(crate) struct Sounds {
 	sounds: Arena<Box<dyn Sound>>,
-	unused_sound_producer: HeapProducer<Box<dyn Sound>>,
+	unused_sound_producer: HeapProducer<(Key, Box<dyn Sound>)>,
 }
 
 impl Sounds {
-	pub fn new(capacity: usize, unused_sound_producer: HeapProducer<Box<dyn Sound>>) -> Self {
+	pub fn new(
+		capacity: usize,
+		unused_sound_producer: HeapProducer<(Key, Box<dyn Sound>)>,
+	) -> Self {
 		Self {
 			sounds: Arena::new(capacity),
 			unused_sound_producer,
@@ -36,8 +40,8 @@ impl Sounds {
 		if self.unused_sound_producer.is_full() {
 			return;
 		}
-		for (_, sound) in self.sounds.drain_filter(|sound| sound.finished()) {
-			if self.unused_sound_producer.push(sound).is_err() {
+		for (key, sound) in self.sounds.drain_filter(|sound| sound.finished()) {
+			if self.unused_sound_producer.push((key, sound)).is_err() {
 				panic!("Unused sound producer is full")
 			}
 			if self.unused_sound_producer.is_full() {
@@ -52,6 +56,20 @@ impl Sounds {
 				.sounds
 				.insert_with_key(key, sound)
 				.expect("Sound arena is full"),
+			SoundCommand::PauseAll(track_id, fade_out_tween) => {
+				for (_, sound) in &mut self.sounds {
+					if outputs_to_track(sound.as_mut(), track_id) {
+						sound.on_pause_all(fade_out_tween);
+					}
+				}
+			}
+			SoundCommand::ResumeAll(track_id, fade_in_tween) => {
+				for (_, sound) in &mut self.sounds {
+					if outputs_to_track(sound.as_mut(), track_id) {
+						sound.on_resume_all(fade_in_tween);
+					}
+				}
+			}
 		}
 	}
 
@@ -89,3 +107,12 @@ impl Sounds {
 		}
 	}
 }
+
+/// Returns `true` if the sound outputs directly to the given track,
+/// or if no track is specified.
+fn outputs_to_track(sound: &mut dyn Sound, track_id: Option<TrackId>) -> bool {
+	match track_id {
+		Some(track_id) => sound.output_destination() == OutputDestination::Track(track_id),
+		None => true,
+	}
+}
@@ -19,6 +19,8 @@ use crate::{
 
 pub(crate) enum SoundCommand {
 	Add(Key, Box<dyn Sound>),
+	PauseAll(Option<TrackId>, Tween),
+	ResumeAll(Option<TrackId>, Tween),
 }
 
 pub(crate) enum MixerCommand {
@@ -18,15 +18,14 @@ pub use handle::*;
 use ringbuf::HeapConsumer;
 
 use crate::{
-	clock::clock_info::{ClockInfoProvider, WhenToStart},
-	tween::{Tween, Tweenable},
+	clock::clock_info::ClockInfoProvider,
+	tween::{Parameter, Value},
 };
 
 use super::{value_provider::ModulatorValueProvider, Modulator};
 
 struct Tweener {
-	state: State,
-	value: f64,
+	value: Parameter,
 	command_consumer: HeapConsumer<Command>,
 	shared: Arc<TweenerShared>,
 }
@@ -38,27 +37,21 @@ impl Tweener {
 		shared: Arc<TweenerShared>,
 	) -> Self {
 		Self {
-			state: State::Idle,
-			value: initial_value,
+			value: Parameter::new(Value::Fixed(initial_value), initial_value),
 			command_consumer,
 			shared,
 		}
 	}
-
-	fn set(&mut self, target: f64, tween: Tween) {
-		self.state = State::Tweening {
-			values: (self.value, target),
-			time: 0.0,
-			tween,
-		}
-	}
 }
 
 impl Modulator for Tweener {
 	fn on_start_processing(&mut self) {
 		while let Some(command) = self.command_consumer.pop() {
 			match command {
-				Command::Set { target, tween } => self.set(target, tween),
+				Command::Set { target, tween } => self.value.set(Value::Fixed(target), tween),
+				Command::Automate(automation) => self.value.automate(automation),
+				Command::CancelTween => self.value.cancel_tween(),
+				Command::SetImmediate(value) => self.value.set_immediate(value),
 			}
 		}
 	}
@@ -67,28 +60,14 @@ impl Modulator for Tweener {
 		&mut self,
 		dt: f64,
 		clock_info_provider: &ClockInfoProvider,
-		_modulator_value_provider: &ModulatorValueProvider,
+		modulator_value_provider: &ModulatorValueProvider,
 	) {
-		if let State::Tweening {
-			values,
-			time,
-			tween,
-		} = &mut self.state
-		{
-			if clock_info_provider.when_to_start(tween.start_time) == WhenToStart::Now {
-				*time += dt;
-				if *time >= tween.duration.as_secs_f64() {
-					self.value = values.1;
-					self.state = State::Idle;
-				} else {
-					self.value = Tweenable::interpolate(values.0, values.1, tween.value(*time));
-				}
-			}
-		}
+		self.value
+			.update(dt, clock_info_provider, modulator_value_provider);
 	}
 
 	fn value(&self) -> f64 {
-		self.value
+		self.value.value()
 	}
 
 	fn finished(&self) -> bool {
@@ -96,15 +75,6 @@ impl Modulator for Tweener {
 	}
 }
 
-enum State {
-	Idle,
-	Tweening {
-		values: (f64, f64),
-		time: f64,
-		tween: Tween,
-	},
-}
-
 struct TweenerShared {
 	removed: AtomicBool,
 }
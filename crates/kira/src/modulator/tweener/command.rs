@@ -1,5 +1,12 @@
-use crate::tween::Tween;
+use crate::tween::{Automation, Tween};
 
 pub(super) enum Command {
 	Set { target: f64, tween: Tween },
+	// the automation is stored inline rather than boxed so that
+	// the audio thread doesn't have to deallocate it. this makes each
+	// command slot a few hundred bytes larger, which is fine for the
+	// small command buffer each tweener has.
+	Automate(Automation<f64>),
+	CancelTween,
+	SetImmediate(f64),
 }
@@ -2,7 +2,11 @@ use std::sync::{atomic::Ordering, Arc};
 
 use ringbuf::HeapProducer;
 
-use crate::{modulator::ModulatorId, tween::Tween, CommandError};
+use crate::{
+	modulator::ModulatorId,
+	tween::{Automation, Tween},
+	CommandError,
+};
 
 use super::{command::Command, TweenerShared};
 
@@ -26,6 +30,32 @@ impl TweenerHandle {
 			.push(Command::Set { target, tween })
 			.map_err(|_| CommandError::CommandQueueFull)
 	}
+
+	/// Moves the value through the keyframes of an [`Automation`],
+	/// starting from the current value.
+	///
+	/// Any transition that's in progress will be interrupted.
+	pub fn automate(&mut self, automation: Automation<f64>) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Automate(automation))
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
+	/// Stops any in-progress transition or automation and holds
+	/// the current value.
+	pub fn cancel_tween(&mut self) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::CancelTween)
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
+	/// Stops any in-progress transition or automation and immediately
+	/// sets the value.
+	pub fn set_immediate(&mut self, value: f64) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::SetImmediate(value))
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
 }
 
 impl From<&TweenerHandle> for ModulatorId {
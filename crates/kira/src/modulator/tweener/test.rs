@@ -11,7 +11,7 @@ use crate::{
 		tweener::TweenerBuilder, value_provider::MockModulatorValueProviderBuilder,
 		ModulatorBuilder, ModulatorId,
 	},
-	tween::Tween,
+	tween::{Automation, Easing, Tween},
 	StartTime,
 };
 
@@ -175,6 +175,72 @@ fn waits_for_start_time() {
 	assert_eq!(tweener.value(), 1.0);
 }
 
+/// Tests that a Tweener can be moved through an automation.
+#[test]
+#[allow(clippy::float_cmp)]
+fn automation() {
+	let (mut tweener, mut handle) =
+		TweenerBuilder { initial_value: 0.0 }.build(generate_fake_modulator_id());
+	let clock_info_provider = MockClockInfoProviderBuilder::new(1).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	handle
+		.automate(
+			Automation::new()
+				.keyframe(1.0, Duration::from_secs(2), Easing::Linear)
+				.keyframe(-1.0, Duration::from_secs(1), Easing::Linear),
+		)
+		.unwrap();
+	tweener.on_start_processing();
+
+	for expected_value in [0.5, 1.0, -1.0, -1.0] {
+		tweener.update(1.0, &clock_info_provider, &modulator_value_provider);
+		assert_eq!(tweener.value(), expected_value);
+	}
+}
+
+/// Tests that a Tweener's transition can be cancelled, and that
+/// its value can be set immediately.
+#[test]
+#[allow(clippy::float_cmp)]
+fn cancel_tween_and_set_immediate() {
+	let (mut tweener, mut handle) =
+		TweenerBuilder { initial_value: 0.0 }.build(generate_fake_modulator_id());
+	let clock_info_provider = MockClockInfoProviderBuilder::new(1).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	handle
+		.set(
+			1.0,
+			Tween {
+				duration: Duration::from_secs(2),
+				..Default::default()
+			},
+		)
+		.unwrap();
+	tweener.on_start_processing();
+	tweener.update(1.0, &clock_info_provider, &modulator_value_provider);
+	assert_eq!(tweener.value(), 0.5);
+
+	handle.cancel_tween().unwrap();
+	tweener.on_start_processing();
+	tweener.update(1.0, &clock_info_provider, &modulator_value_provider);
+	assert_eq!(tweener.value(), 0.5);
+
+	handle.set_immediate(-1.0).unwrap();
+	tweener.on_start_processing();
+	assert_eq!(tweener.value(), -1.0);
+	tweener.update(1.0, &clock_info_provider, &modulator_value_provider);
+	assert_eq!(tweener.value(), -1.0);
+}
+
+/// Tests that storing automations inline doesn't make tweener
+/// commands unreasonably large.
+#[test]
+fn command_size() {
+	assert!(std::mem::size_of::<super::Command>() <= 1024);
+}
+
 fn generate_fake_modulator_id() -> ModulatorId {
 	let arena = Arena::<()>::new(1);
 	ModulatorId(arena.controller().try_reserve().unwrap())
@@ -16,6 +16,7 @@ create your own types that implement the [`SoundData`] and [`Sound`] traits.
 
 #[cfg(feature = "symphonia")]
 mod error;
+mod markers;
 mod playback_position;
 mod playback_rate;
 pub mod static_sound;
@@ -23,19 +24,27 @@ pub mod static_sound;
 pub mod streaming;
 #[cfg(feature = "symphonia")]
 mod symphonia;
+#[cfg(all(test, feature = "wav"))]
+pub(crate) mod test_util;
 mod transport;
 mod util;
 
-use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::{
+	ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
+	time::Duration,
+};
+
+use atomic_arena::Key;
 
 #[cfg(feature = "symphonia")]
 pub use error::*;
+pub use markers::*;
 pub use playback_position::*;
 pub use playback_rate::*;
 
 use crate::{
 	clock::clock_info::ClockInfoProvider, dsp::Frame,
-	modulator::value_provider::ModulatorValueProvider, OutputDestination,
+	modulator::value_provider::ModulatorValueProvider, tween::Tween, OutputDestination,
 };
 
 /// A source of audio that is loaded, but not yet playing.
@@ -55,6 +64,43 @@ pub trait SoundData {
 	/// [`AudioManager::play`](crate::manager::AudioManager::play).
 	#[allow(clippy::type_complexity)]
 	fn into_sound(self) -> Result<(Box<dyn Sound>, Self::Handle), Self::Error>;
+
+	/// Returns information about the audio that can be shown
+	/// by [`AudioManager::playing_sounds`](crate::manager::AudioManager::playing_sounds).
+	///
+	/// This is called before [`into_sound`](SoundData::into_sound).
+	/// By default, no information is reported. Since [`SoundInfo`] may
+	/// gain more fields in the future, implementations outside of Kira
+	/// should start from [`SoundInfo::default`] and set the fields they
+	/// know about.
+	fn info(&self) -> SoundInfo {
+		SoundInfo::default()
+	}
+}
+
+/// A unique identifier for a sound that's been sent to the audio thread.
+///
+/// The identifier stays the same for as long as the sound is loaded, so
+/// it can be used to tell sounds apart across calls to
+/// [`AudioManager::playing_sounds`](crate::manager::AudioManager::playing_sounds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SoundId(pub(crate) Key);
+
+/// Information about a sound, returned by
+/// [`AudioManager::playing_sounds`](crate::manager::AudioManager::playing_sounds).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct SoundInfo {
+	/// The length of the audio, if known.
+	pub duration: Option<Duration>,
+	/// The sample rate of the audio (in Hz), if known.
+	pub sample_rate: Option<u32>,
+	/// An estimate of how much memory (in bytes) the audio data uses.
+	///
+	/// Some sounds, like [`StaticSoundData`](static_sound::StaticSoundData),
+	/// share their audio data with their clones, so adding up the memory
+	/// usage of multiple sounds can overestimate the total.
+	pub memory_usage: usize,
 }
 
 /// An actively playing sound.
@@ -75,6 +121,23 @@ pub trait Sound: Send {
 	/// but not for every single audio sample.
 	fn on_start_processing(&mut self) {}
 
+	/// Called when all sounds (or all sounds on this sound's mixer track)
+	/// are paused using [`AudioManager::pause_all`](crate::manager::AudioManager::pause_all)
+	/// or [`TrackHandle::pause_all`](crate::track::TrackHandle::pause_all).
+	///
+	/// Sounds that are currently playing should fade out and pause, and
+	/// remember that they were paused this way so they can be resumed by
+	/// [`on_resume_all`](Sound::on_resume_all).
+	fn on_pause_all(&mut self, fade_out_tween: Tween) {}
+
+	/// Called when all sounds (or all sounds on this sound's mixer track)
+	/// are resumed using [`AudioManager::resume_all`](crate::manager::AudioManager::resume_all)
+	/// or [`TrackHandle::resume_all`](crate::track::TrackHandle::resume_all).
+	///
+	/// Sounds that were paused by [`on_pause_all`](Sound::on_pause_all) should
+	/// resume and fade in. Sounds that were paused some other way should stay paused.
+	fn on_resume_all(&mut self, fade_in_tween: Tween) {}
+
 	/// Produces the next [`Frame`] of audio.
 	///
 	/// `dt` is the time that's elapsed since the previous round of
@@ -14,6 +14,13 @@ pub enum FromFileError {
 	/// The audio uses an unsupported channel configuration. Only
 	/// mono and stereo audio is supported.
 	UnsupportedChannelConfiguration,
+	/// The file is in a format that Kira can decode, but support for it
+	/// was not enabled. The string is the name of the Cargo feature that
+	/// enables the format.
+	FormatNotEnabled(&'static str),
+	/// The file was decoded successfully, but it doesn't contain any
+	/// audio. This can happen if the file is truncated or corrupt.
+	EmptyAudioData,
 	/// An error occurred while reading the file from the filesystem.
 	IoError(std::io::Error),
 	/// An error occurred when parsing the file.
@@ -35,6 +42,11 @@ impl Display for FromFileError {
 			FromFileError::UnsupportedChannelConfiguration => {
 				f.write_str("Only mono and stereo audio is supported")
 			}
+			FromFileError::FormatNotEnabled(feature) => f.write_fmt(format_args!(
+				"Support for this audio format is not enabled. Enable the \"{}\" feature to load it",
+				feature
+			)),
+			FromFileError::EmptyAudioData => f.write_str("The file does not contain any audio"),
 			FromFileError::IoError(error) => error.fmt(f),
 			FromFileError::SymphoniaError(error) => error.fmt(f),
 		}
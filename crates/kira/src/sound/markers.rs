@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod test;
+
+use std::{
+	collections::HashMap,
+	error::Error,
+	fmt::{Display, Formatter},
+};
+
+use super::{EndPosition, PlaybackPosition, Region};
+
+/**
+Named positions in a piece of audio.
+
+Markers let gameplay code refer to meaningful points in a sound
+(like the start of a verse) by name instead of by raw timestamps.
+Marker names are looked up when they're used, and looking up a
+name that doesn't exist returns an [`UnknownMarkerError`].
+
+# Examples
+
+Looping between two markers:
+
+```
+use kira::sound::{
+	static_sound::{StaticSoundData, StaticSoundSettings},
+	Markers,
+};
+
+let mut markers = Markers::new();
+markers.insert("verse_start", 8.0);
+markers.insert("verse_end", 24.0);
+
+let settings = StaticSoundSettings::new()
+	.playback_region(markers.position("verse_start")?..)
+	.loop_region(markers.region("verse_start", "verse_end")?);
+# Result::<(), Box<dyn std::error::Error>>::Ok(())
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Markers {
+	positions: HashMap<String, f64>,
+}
+
+impl Markers {
+	/// Creates a new, empty set of markers.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a marker at the given position (in seconds).
+	///
+	/// If a marker with the same name already exists, it's moved to the
+	/// new position, and its old position is returned.
+	pub fn insert(&mut self, name: impl Into<String>, position: f64) -> Option<f64> {
+		self.positions.insert(name.into(), position)
+	}
+
+	/// Removes a marker, returning its position if it existed.
+	pub fn remove(&mut self, name: &str) -> Option<f64> {
+		self.positions.remove(name)
+	}
+
+	/// Returns the position (in seconds) of the marker with the given
+	/// name, or `None` if there's no marker with that name.
+	pub fn get(&self, name: &str) -> Option<f64> {
+		self.positions.get(name).copied()
+	}
+
+	/// Returns the position (in seconds) of the marker with the given
+	/// name.
+	pub fn position(&self, name: &str) -> Result<f64, UnknownMarkerError> {
+		self.get(name).ok_or_else(|| UnknownMarkerError {
+			name: name.to_string(),
+		})
+	}
+
+	/// Returns the [`Region`] between two markers.
+	pub fn region(&self, start: &str, end: &str) -> Result<Region, UnknownMarkerError> {
+		Ok(Region {
+			start: PlaybackPosition::Seconds(self.position(start)?),
+			end: EndPosition::Custom(PlaybackPosition::Seconds(self.position(end)?)),
+		})
+	}
+
+	/// Returns an iterator over the names and positions of the markers.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, f64)> {
+		self.positions
+			.iter()
+			.map(|(name, position)| (name.as_str(), *position))
+	}
+}
+
+/// An error that occurs when looking up a marker that doesn't exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMarkerError {
+	/// The name of the marker that couldn't be found.
+	pub name: String,
+}
+
+impl Display for UnknownMarkerError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_fmt(format_args!("There is no marker named \"{}\"", self.name))
+	}
+}
+
+impl Error for UnknownMarkerError {}
@@ -0,0 +1,41 @@
+use crate::sound::{EndPosition, PlaybackPosition, Region};
+
+use super::{Markers, UnknownMarkerError};
+
+/// Tests that markers can be looked up by name.
+#[test]
+#[allow(clippy::float_cmp)]
+fn looks_up_markers() {
+	let mut markers = Markers::new();
+	markers.insert("verse_start", 8.0);
+	markers.insert("verse_end", 24.0);
+	assert_eq!(markers.position("verse_start"), Ok(8.0));
+	assert_eq!(
+		markers.region("verse_start", "verse_end"),
+		Ok(Region {
+			start: PlaybackPosition::Seconds(8.0),
+			end: EndPosition::Custom(PlaybackPosition::Seconds(24.0)),
+		})
+	);
+	assert_eq!(markers.insert("verse_end", 32.0), Some(24.0));
+	assert_eq!(markers.get("verse_end"), Some(32.0));
+}
+
+/// Tests that looking up a marker that doesn't exist returns
+/// an error with the marker's name.
+#[test]
+fn unknown_markers() {
+	let mut markers = Markers::new();
+	markers.insert("verse_start", 8.0);
+	let unknown = UnknownMarkerError {
+		name: "chorus".to_string(),
+	};
+	assert_eq!(markers.position("chorus"), Err(unknown.clone()));
+	assert_eq!(
+		markers.region("verse_start", "chorus"),
+		Err(unknown.clone())
+	);
+	assert_eq!(markers.region("chorus", "verse_start"), Err(unknown));
+	assert_eq!(markers.remove("verse_start"), Some(8.0));
+	assert!(markers.position("verse_start").is_err());
+}
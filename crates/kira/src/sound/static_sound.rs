@@ -24,11 +24,13 @@ when starting and seeking, but they use a lot more memory.
 */
 
 mod data;
+mod generator;
 mod handle;
 mod settings;
 mod sound;
 
 pub use data::*;
+pub use generator::*;
 pub use handle::*;
 pub use settings::*;
 
@@ -42,6 +44,8 @@ use super::{PlaybackRate, Region};
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Command {
 	SetVolume(Value<Volume>, Tween),
+	CancelVolumeTween,
+	SetVolumeImmediate(Volume),
 	SetPlaybackRate(Value<PlaybackRate>, Tween),
 	SetPanning(Value<f64>, Tween),
 	SetPlaybackRegion(Region),
@@ -49,6 +53,7 @@ enum Command {
 	Pause(Tween),
 	Resume(Tween),
 	Stop(Tween),
+	Release,
 	SeekBy(f64),
 	SeekTo(f64),
 }
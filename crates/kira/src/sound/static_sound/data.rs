@@ -6,6 +6,7 @@ mod test;
 
 use std::{
 	fmt::{Debug, Formatter},
+	mem::size_of,
 	sync::Arc,
 	time::Duration,
 };
@@ -14,33 +15,235 @@ use ringbuf::HeapRb;
 
 use crate::{
 	dsp::Frame,
-	sound::{Sound, SoundData},
+	sound::{
+		transport::loop_region_frames, Markers, PlaybackPosition, Region, Sound, SoundData,
+		SoundInfo, UnknownMarkerError,
+	},
 };
 
 use super::{handle::StaticSoundHandle, sound::StaticSound, StaticSoundSettings};
 
 const COMMAND_BUFFER_CAPACITY: usize = 8;
+/// How far away from a loop point (in seconds) to look for a zero crossing.
+const MAX_ZERO_CROSSING_DISTANCE: f64 = 0.05;
 
-/// A piece of audio loaded into memory all at once.
-///
-/// These can be cheaply cloned, as the audio data is shared
-/// among all clones.
+/// Measurements of the loudness of a [`StaticSoundData`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoundStats {
+	/// The highest absolute sample value across both channels.
+	pub peak_amplitude: f32,
+	/// The root mean square amplitude across both channels.
+	pub rms: f32,
+}
+
+/**
+A piece of audio loaded into memory all at once.
+
+These can be cheaply cloned, as the audio data is shared
+among all clones.
+
+The [`settings`](StaticSoundData::settings) can be changed at any time
+without reloading the audio. Changes only apply to sounds played
+afterward; sounds that are already playing keep the settings they
+were started with.
+
+# Examples
+
+Changing the loop region of a sound after it's loaded:
+
+```
+use kira::{
+	dsp::Frame,
+	sound::static_sound::{StaticSoundData, StaticSoundSettings},
+};
+
+let mut sound_data = StaticSoundData::from_frames(
+	48_000,
+	vec![Frame::ZERO; 96_000],
+	StaticSoundSettings::new().loop_region(0.0..),
+);
+// later...
+sound_data.settings = sound_data.settings.loop_region(0.5..1.5);
+```
+*/
 #[derive(Clone, PartialEq)]
 pub struct StaticSoundData {
 	/// The sample rate of the audio (in Hz).
 	pub sample_rate: u32,
 	/// The raw samples that make up the audio.
+	///
+	/// The frames are stored directly in the `Arc` without an extra
+	/// `Vec` in between, and playing the sound doesn't copy them, so
+	/// every instance of the sound reads from the same memory.
 	pub frames: Arc<[Frame]>,
 	/// Settings for the sound.
 	pub settings: StaticSoundSettings,
 }
 
 impl StaticSoundData {
+	/**
+	Creates a [`StaticSoundData`] from frames of audio.
+
+	`frames` can be anything that converts into an `Arc<[Frame]>`, like a
+	`Vec<Frame>`. Passing an existing `Arc<[Frame]>` lets multiple sounds
+	share the same audio data without copying it.
+
+	# Examples
+
+	Sharing generated audio between two sounds:
+
+	```
+	use std::sync::Arc;
+
+	use kira::{
+		dsp::Frame,
+		sound::static_sound::{StaticSoundData, StaticSoundSettings},
+	};
+
+	let frames: Arc<[Frame]> = (0..48_000)
+		.map(|i| Frame::from_mono((i as f32 * 440.0 / 48_000.0 * std::f32::consts::TAU).sin()))
+		.collect();
+	let quiet = StaticSoundData::from_frames(48_000, frames.clone(), StaticSoundSettings::new().volume(0.5));
+	let loud = StaticSoundData::from_frames(48_000, frames, StaticSoundSettings::new());
+	assert!(Arc::ptr_eq(&quiet.frames, &loud.frames));
+	```
+	*/
+	pub fn from_frames(
+		sample_rate: u32,
+		frames: impl Into<Arc<[Frame]>>,
+		settings: StaticSoundSettings,
+	) -> Self {
+		Self {
+			sample_rate,
+			frames: frames.into(),
+			settings,
+		}
+	}
+
+	/**
+	Creates a [`StaticSoundData`] from samples that can be converted
+	into [`Frame`]s.
+
+	# Examples
+
+	Creating a sound from mono samples:
+
+	```
+	use kira::sound::static_sound::{StaticSoundData, StaticSoundSettings};
+
+	let samples = vec![0.0f32, 0.5, 1.0, 0.5, 0.0];
+	let sound_data = StaticSoundData::from_samples(48_000, samples, StaticSoundSettings::new());
+	```
+	*/
+	pub fn from_samples<S: Into<Frame>>(
+		sample_rate: u32,
+		samples: impl IntoIterator<Item = S>,
+		settings: StaticSoundSettings,
+	) -> Self {
+		Self::from_frames(
+			sample_rate,
+			samples
+				.into_iter()
+				.map(Into::into)
+				.collect::<Arc<[Frame]>>(),
+			settings,
+		)
+	}
+
 	/// Returns the duration of the audio.
 	pub fn duration(&self) -> Duration {
 		Duration::from_secs_f64(self.frames.len() as f64 / self.sample_rate as f64)
 	}
 
+	/// Returns the peak and root mean square amplitude of the audio
+	/// across both channels.
+	///
+	/// Both are measured in a single pass over the audio, so this takes
+	/// O(n) time in the number of frames. The result isn't cached, so
+	/// consider storing it if it's needed often.
+	pub fn stats(&self) -> SoundStats {
+		let (peak_amplitude, sum_of_squares) =
+			self.frames
+				.iter()
+				.fold((0.0f32, 0.0f64), |(peak, sum), frame| {
+					(
+						peak.max(frame.left.abs()).max(frame.right.abs()),
+						sum + (frame.left as f64).powi(2) + (frame.right as f64).powi(2),
+					)
+				});
+		let rms = if self.frames.is_empty() {
+			0.0
+		} else {
+			(sum_of_squares / (self.frames.len() * 2) as f64).sqrt() as f32
+		};
+		SoundStats {
+			peak_amplitude,
+			rms,
+		}
+	}
+
+	/**
+	Splits the audio into `buckets` equally sized chunks and returns
+	the lowest and highest sample value (across both channels) in each
+	chunk.
+
+	This is useful for drawing an overview of the waveform. If there
+	are more buckets than frames, some buckets will share the same frame.
+	If the audio is empty, every bucket is `(0.0, 0.0)`.
+
+	# Examples
+
+	```
+	use kira::sound::static_sound::{StaticSoundData, StaticSoundSettings};
+
+	let sound_data = StaticSoundData::from_samples(
+		48_000,
+		vec![0.0f32, 0.5, -0.25, 1.0],
+		StaticSoundSettings::new(),
+	);
+	assert_eq!(sound_data.downsampled_peaks(2), vec![(0.0, 0.5), (-0.25, 1.0)]);
+	```
+	*/
+	pub fn downsampled_peaks(&self, buckets: usize) -> Vec<(f32, f32)> {
+		let num_frames = self.frames.len();
+		(0..buckets)
+			.map(|bucket| {
+				if num_frames == 0 {
+					return (0.0, 0.0);
+				}
+				let start = bucket_boundary(bucket, num_frames, buckets).min(num_frames - 1);
+				let end = bucket_boundary(bucket + 1, num_frames, buckets).max(start + 1);
+				self.frames[start..end].iter().fold(
+					(f32::INFINITY, f32::NEG_INFINITY),
+					|(min, max), frame| {
+						(
+							min.min(frame.left).min(frame.right),
+							max.max(frame.left).max(frame.right),
+						)
+					},
+				)
+			})
+			.collect()
+	}
+
+	/// Returns the start and end frames of the portion of the sound that
+	/// will be looped, or `None` if the sound doesn't loop. The end frame
+	/// is *exclusive*.
+	///
+	/// If [`StaticSoundSettings::snap_loop_to_zero_crossings`] is enabled,
+	/// these are the loop points after they've been moved to the nearest
+	/// zero crossings.
+	pub fn loop_region_frames(&self) -> Option<(i64, i64)> {
+		self.settings.loop_region.map(|loop_region| {
+			self.snap_loop_region(loop_region_frames(
+				loop_region,
+				self.sample_rate,
+				self.frames.len(),
+			))
+		})
+	}
+
 	/// Returns a clone of the `StaticSoundData` with the specified settings.
 	pub fn with_settings(&self, settings: StaticSoundSettings) -> Self {
 		Self {
@@ -49,8 +252,42 @@ impl StaticSoundData {
 		}
 	}
 
-	/// Returns a clone of the `StaticSoundData` with the modified settings from
-	/// the given function.
+	/**
+	Returns a clone of the `StaticSoundData` with the modified settings from
+	the given function.
+
+	This is useful for changing how a single instance of a sound plays
+	without affecting the settings the sound was loaded with.
+
+	# Examples
+
+	Routing the same sound to different mixer tracks:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::static_sound::{StaticSoundData, StaticSoundSettings},
+		track::TrackBuilder,
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let sfx_track = manager.add_sub_track(TrackBuilder::new())?;
+	let muffled_track = manager.add_sub_track(TrackBuilder::new())?;
+	let gunshot = StaticSoundData::from_file(
+		"gunshot.ogg",
+		StaticSoundSettings::new().output_destination(&sfx_track),
+	)?;
+	# let behind_wall = true;
+	if behind_wall {
+		manager.play(gunshot.with_modified_settings(|settings| {
+			settings.output_destination(&muffled_track)
+		}))?;
+	} else {
+		manager.play(gunshot.clone())?;
+	}
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
 	pub fn with_modified_settings(
 		&self,
 		f: impl FnOnce(StaticSoundSettings) -> StaticSoundSettings,
@@ -58,6 +295,77 @@ impl StaticSoundData {
 		self.with_settings(f(self.settings))
 	}
 
+	/// Returns a clone of the `StaticSoundData` that starts playing
+	/// from the marker in `markers` with the given name.
+	pub fn start_at_marker(
+		&self,
+		markers: &Markers,
+		name: &str,
+	) -> Result<Self, UnknownMarkerError> {
+		let start = PlaybackPosition::Seconds(markers.position(name)?);
+		Ok(self.with_modified_settings(|settings| {
+			settings.playback_region(Region {
+				start,
+				end: settings.playback_region.end,
+			})
+		}))
+	}
+
+	/**
+	Returns a clone of the `StaticSoundData` that loops between the
+	markers in `markers` with the given names.
+
+	# Examples
+
+	```
+	use kira::{
+		dsp::Frame,
+		sound::{
+			static_sound::{StaticSoundData, StaticSoundSettings},
+			Markers,
+		},
+	};
+
+	let sound_data = StaticSoundData::from_frames(
+		48_000,
+		vec![Frame::ZERO; 48_000 * 30],
+		StaticSoundSettings::new(),
+	);
+	let mut markers = Markers::new();
+	markers.insert("verse_start", 8.0);
+	markers.insert("verse_end", 24.0);
+	let verse = sound_data.loop_between_markers(&markers, "verse_start", "verse_end")?;
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn loop_between_markers(
+		&self,
+		markers: &Markers,
+		start: &str,
+		end: &str,
+	) -> Result<Self, UnknownMarkerError> {
+		let loop_region = markers.region(start, end)?;
+		Ok(self.with_modified_settings(|settings| settings.loop_region(loop_region)))
+	}
+
+	/// Moves the start and end frames of a loop region to the nearest
+	/// zero crossings if the settings ask for it.
+	pub(super) fn snap_loop_region(&self, loop_region: (i64, i64)) -> (i64, i64) {
+		if !self.settings.snap_loop_to_zero_crossings {
+			return loop_region;
+		}
+		let max_distance = (self.sample_rate as f64 * MAX_ZERO_CROSSING_DISTANCE) as i64;
+		let (start, end) = loop_region;
+		let snapped_start =
+			nearest_zero_crossing(&self.frames, start, max_distance).unwrap_or(start);
+		let snapped_end = nearest_zero_crossing(&self.frames, end, max_distance).unwrap_or(end);
+		if snapped_start < snapped_end {
+			(snapped_start, snapped_end)
+		} else {
+			loop_region
+		}
+	}
+
 	pub(super) fn split(self) -> (StaticSound, StaticSoundHandle) {
 		let (command_producer, command_consumer) = HeapRb::new(COMMAND_BUFFER_CAPACITY).split();
 		let sound = StaticSound::new(self, command_consumer);
@@ -82,6 +390,14 @@ impl SoundData for StaticSoundData {
 		let (sound, handle) = self.split();
 		Ok((Box::new(sound), handle))
 	}
+
+	fn info(&self) -> SoundInfo {
+		SoundInfo {
+			duration: Some(self.duration()),
+			sample_rate: Some(self.sample_rate),
+			memory_usage: self.frames.len() * size_of::<Frame>(),
+		}
+	}
 }
 
 impl Debug for StaticSoundData {
@@ -99,6 +415,38 @@ impl Debug for StaticSoundData {
 	}
 }
 
+/// Returns the index of the first frame of a bucket when splitting
+/// `num_frames` frames into `buckets` buckets.
+///
+/// The multiplication is done with `u64`s so it doesn't overflow
+/// on 32-bit targets.
+fn bucket_boundary(bucket: usize, num_frames: usize, buckets: usize) -> usize {
+	(bucket as u64 * num_frames as u64 / buckets as u64) as usize
+}
+
+/// Finds the zero crossing closest to `index` that's at most `max_distance`
+/// frames away. A zero crossing at `index` means that both channels cross
+/// or touch zero between the frames at `index - 1` and `index`.
+fn nearest_zero_crossing(frames: &[Frame], index: i64, max_distance: i64) -> Option<i64> {
+	let is_zero_crossing = |index: i64| -> bool {
+		if index < 1 || index >= frames.len() as i64 {
+			return false;
+		}
+		let previous = frames[index as usize - 1];
+		let current = frames[index as usize];
+		previous.left * current.left <= 0.0 && previous.right * current.right <= 0.0
+	};
+	(0..=max_distance).find_map(|distance| {
+		if is_zero_crossing(index - distance) {
+			Some(index - distance)
+		} else if is_zero_crossing(index + distance) {
+			Some(index + distance)
+		} else {
+			None
+		}
+	})
+}
+
 struct FramesDebug {
 	len: usize,
 }
@@ -3,19 +3,27 @@ use std::io::Cursor;
 use symphonia::core::io::{MediaSource, MediaSourceStream};
 
 use crate::sound::{
-	static_sound::StaticSoundSettings, symphonia::load_frames_from_buffer_ref, FromFileError,
+	static_sound::StaticSoundSettings,
+	symphonia::{check_format_enabled, load_frames_from_buffer_ref},
+	FromFileError,
 };
 
 use super::StaticSoundData;
 
 impl StaticSoundData {
 	/// Loads an audio file into a [`StaticSoundData`].
+	///
+	/// Returns [`FromFileError::FormatNotEnabled`] if the file extension
+	/// belongs to an audio format whose feature is disabled, and
+	/// [`FromFileError::EmptyAudioData`] if the file doesn't contain
+	/// any audio.
 	#[cfg(not(target_arch = "wasm32"))]
 	#[cfg_attr(docsrs, doc(cfg(all(feature = "symphonia", not(wasm32)))))]
 	pub fn from_file(
 		path: impl AsRef<std::path::Path>,
 		settings: StaticSoundSettings,
 	) -> Result<Self, FromFileError> {
+		check_format_enabled(path.as_ref())?;
 		Self::from_media_source(std::fs::File::open(path)?, settings)
 	}
 
@@ -79,6 +87,9 @@ impl StaticSoundData {
 				},
 			}
 		}
+		if frames.is_empty() {
+			return Err(FromFileError::EmptyAudioData);
+		}
 		Ok(Self {
 			sample_rate,
 			frames: frames.into(),
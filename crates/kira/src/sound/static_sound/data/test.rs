@@ -1,8 +1,14 @@
 use std::{sync::Arc, time::Duration};
 
-use crate::dsp::Frame;
+use crate::{
+	dsp::Frame,
+	sound::{EndPosition, Markers, PlaybackPosition, Region, UnknownMarkerError},
+};
 
-use super::StaticSoundData;
+use super::{SoundStats, StaticSoundData, StaticSoundSettings};
+
+#[cfg(feature = "wav")]
+use crate::sound::test_util::wav_file_data;
 
 #[test]
 fn duration() {
@@ -13,3 +19,180 @@ fn duration() {
 	};
 	assert_eq!(static_sound.duration(), Duration::from_secs(4));
 }
+
+#[test]
+fn from_frames_shares_data() {
+	let frames: Arc<[Frame]> = Arc::new([Frame::from_mono(0.5); 4]);
+	let a = StaticSoundData::from_frames(1, frames.clone(), StaticSoundSettings::new());
+	let b = StaticSoundData::from_frames(1, frames.clone(), StaticSoundSettings::new());
+	assert!(Arc::ptr_eq(&a.frames, &frames));
+	assert!(Arc::ptr_eq(&a.frames, &b.frames));
+}
+
+#[test]
+fn from_samples() {
+	let mono = StaticSoundData::from_samples(1, vec![0.25f32, 0.5], StaticSoundSettings::new());
+	assert_eq!(
+		&*mono.frames,
+		&[Frame::from_mono(0.25), Frame::from_mono(0.5)]
+	);
+	let stereo = StaticSoundData::from_samples(
+		1,
+		vec![[0.25f32, -0.25], [0.5, -0.5]],
+		StaticSoundSettings::new(),
+	);
+	assert_eq!(
+		&*stereo.frames,
+		&[Frame::new(0.25, -0.25), Frame::new(0.5, -0.5)]
+	);
+}
+
+#[test]
+fn loop_region_frames() {
+	let data = StaticSoundData {
+		sample_rate: 10,
+		frames: Arc::new([Frame::from_mono(1.0); 20]),
+		settings: StaticSoundSettings::new(),
+	};
+	assert_eq!(data.loop_region_frames(), None);
+	let data = data.with_modified_settings(|settings| settings.loop_region(0.5..));
+	assert_eq!(data.loop_region_frames(), Some((5, 20)));
+}
+
+#[test]
+fn snaps_loop_region_to_zero_crossings() {
+	// the left channel crosses zero before frames 3, 6, 9, and 12, but the
+	// right channel only crosses zero before frames 6 and 12
+	let frames = (0..20)
+		.map(|i| Frame {
+			left: if (i / 3) % 2 == 0 { 1.0 } else { -1.0 },
+			right: if (i / 6) % 2 == 0 { 1.0 } else { -1.0 },
+		})
+		.collect::<Arc<[Frame]>>();
+	let data = StaticSoundData {
+		sample_rate: 100,
+		frames,
+		settings: StaticSoundSettings::new().loop_region(0.04..0.11),
+	};
+	assert_eq!(data.loop_region_frames(), Some((4, 11)));
+	let data = data.with_modified_settings(|settings| settings.snap_loop_to_zero_crossings(true));
+	assert_eq!(data.loop_region_frames(), Some((6, 12)));
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn stats() {
+	let data = StaticSoundData::from_samples(
+		1,
+		vec![[0.5f32, -0.5], [-1.0, 0.5], [0.5, -0.5], [0.5, 0.5]],
+		StaticSoundSettings::new(),
+	);
+	let stats = data.stats();
+	assert_eq!(stats.peak_amplitude, 1.0);
+	assert!((stats.rms - (2.75f32 / 8.0).sqrt()).abs() < 1.0e-6);
+	let empty = StaticSoundData::from_samples(1, Vec::<f32>::new(), StaticSoundSettings::new());
+	assert_eq!(empty.stats(), SoundStats::default());
+}
+
+#[test]
+fn downsampled_peaks() {
+	let data = StaticSoundData::from_samples(
+		1,
+		vec![[0.5f32, -0.5], [-1.0, 0.25], [0.75, 0.0]],
+		StaticSoundSettings::new(),
+	);
+	assert_eq!(data.downsampled_peaks(0), vec![]);
+	assert_eq!(data.downsampled_peaks(1), vec![(-1.0, 0.75)]);
+	assert_eq!(data.downsampled_peaks(2), vec![(-0.5, 0.5), (-1.0, 0.75)]);
+	assert_eq!(
+		data.downsampled_peaks(6),
+		vec![
+			(-0.5, 0.5),
+			(-0.5, 0.5),
+			(-1.0, 0.25),
+			(-1.0, 0.25),
+			(0.0, 0.75),
+			(0.0, 0.75)
+		]
+	);
+	let empty = StaticSoundData::from_samples(1, Vec::<f32>::new(), StaticSoundSettings::new());
+	assert_eq!(empty.downsampled_peaks(2), vec![(0.0, 0.0), (0.0, 0.0)]);
+}
+
+/// Tests that bucket boundaries for long sounds are calculated
+/// without overflowing.
+#[test]
+fn bucket_boundaries_for_long_sounds() {
+	// 10 minutes at 48 kHz
+	let num_frames = 28_800_000;
+	assert_eq!(super::bucket_boundary(0, num_frames, 1000), 0);
+	assert_eq!(super::bucket_boundary(999, num_frames, 1000), 28_771_200);
+	assert_eq!(super::bucket_boundary(1000, num_frames, 1000), num_frames);
+}
+
+/// Tests that loading a file with no audio returns an error
+/// instead of an empty sound.
+#[test]
+#[cfg(feature = "wav")]
+fn rejects_empty_audio_files() {
+	let result = StaticSoundData::from_cursor(
+		std::io::Cursor::new(wav_file_data(&[])),
+		StaticSoundSettings::new(),
+	);
+	assert!(matches!(
+		result,
+		Err(crate::sound::FromFileError::EmptyAudioData)
+	));
+}
+
+/// Tests that a file with a single sample can be loaded.
+#[test]
+#[cfg(feature = "wav")]
+fn loads_single_sample_files() {
+	let data = StaticSoundData::from_cursor(
+		std::io::Cursor::new(wav_file_data(&[i16::MAX / 2])),
+		StaticSoundSettings::new(),
+	)
+	.unwrap();
+	assert_eq!(data.frames.len(), 1);
+}
+
+/// Tests that a sound can be started from a marker and looped between
+/// two markers.
+#[test]
+fn marker_helpers() {
+	let data = StaticSoundData::from_frames(
+		1,
+		vec![Frame::ZERO; 10],
+		StaticSoundSettings::new().playback_region(..8.0),
+	);
+	let mut markers = Markers::new();
+	markers.insert("verse_start", 2.0);
+	markers.insert("verse_end", 6.0);
+	let verse = data.start_at_marker(&markers, "verse_start").unwrap();
+	assert_eq!(
+		verse.settings.playback_region,
+		Region {
+			start: PlaybackPosition::Seconds(2.0),
+			end: EndPosition::Custom(PlaybackPosition::Seconds(8.0)),
+		}
+	);
+	let looping = data
+		.loop_between_markers(&markers, "verse_start", "verse_end")
+		.unwrap();
+	assert_eq!(
+		looping.settings.loop_region,
+		Some(markers.region("verse_start", "verse_end").unwrap())
+	);
+	let unknown = UnknownMarkerError {
+		name: "chorus".to_string(),
+	};
+	assert_eq!(
+		data.start_at_marker(&markers, "chorus"),
+		Err(unknown.clone())
+	);
+	assert_eq!(
+		data.loop_between_markers(&markers, "verse_start", "chorus"),
+		Err(unknown)
+	);
+}
@@ -0,0 +1,266 @@
+#[cfg(test)]
+mod test;
+
+use std::time::Duration;
+
+use crate::{dsp::Frame, modulator::lfo::Waveform};
+
+use super::{StaticSoundData, StaticSoundSettings};
+
+/// A kind of audio signal that can be generated by a [`SignalGenerator`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Signal {
+	/// A periodic tone.
+	Tone {
+		/// The shape of the tone.
+		///
+		/// A [`Waveform::Pulse`] with a width of `0.5` produces
+		/// a square wave.
+		waveform: Waveform,
+		/// The frequency of the tone (in Hz).
+		frequency: f64,
+	},
+	/// Noise with equal energy at all frequencies.
+	WhiteNoise,
+	/// Noise with equal energy in every octave, which sounds
+	/// softer and deeper than white noise.
+	PinkNoise,
+}
+
+/// An attack-decay-sustain-release amplitude envelope.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Envelope {
+	/// How long it takes for the amplitude to rise from silence
+	/// to full volume.
+	pub attack: Duration,
+	/// How long it takes for the amplitude to fall from full volume
+	/// to the sustain level.
+	pub decay: Duration,
+	/// The amplitude (from `0.0` to `1.0`) held after the decay.
+	pub sustain: f64,
+	/// How long it takes for the amplitude to fall to silence
+	/// at the end of the sound.
+	pub release: Duration,
+}
+
+impl Envelope {
+	/// Returns the amplitude of the envelope `time` seconds into a sound
+	/// that lasts `duration` seconds.
+	fn amplitude(&self, time: f64, duration: f64) -> f64 {
+		// the release starts no earlier than the end of the attack, so if
+		// the sound is too short for the whole envelope, the release is
+		// shortened to fit
+		let attack_end = self.attack.as_secs_f64().min(duration);
+		let release_start = (duration - self.release.as_secs_f64()).max(attack_end);
+		let release = duration - release_start;
+		if time < release_start || release <= 0.0 {
+			return self.amplitude_before_release(time);
+		}
+		let release_progress = ((time - release_start) / release).min(1.0);
+		self.amplitude_before_release(release_start) * (1.0 - release_progress)
+	}
+
+	fn amplitude_before_release(&self, time: f64) -> f64 {
+		let attack = self.attack.as_secs_f64();
+		let decay = self.decay.as_secs_f64();
+		if time < attack {
+			time / attack
+		} else if time < attack + decay {
+			1.0 - (1.0 - self.sustain) * (time - attack) / decay
+		} else {
+			self.sustain
+		}
+	}
+}
+
+impl Default for Envelope {
+	fn default() -> Self {
+		Self {
+			attack: Duration::ZERO,
+			decay: Duration::ZERO,
+			sustain: 1.0,
+			release: Duration::ZERO,
+		}
+	}
+}
+
+/**
+Generates simple audio signals, like tones and noise.
+
+# Examples
+
+Generating a short plucked square wave:
+
+```
+use std::time::Duration;
+
+use kira::{
+	modulator::lfo::Waveform,
+	sound::static_sound::{Envelope, Signal, SignalGenerator, StaticSoundData, StaticSoundSettings},
+};
+
+let generator = SignalGenerator::new(
+	Signal::Tone {
+		waveform: Waveform::Pulse { width: 0.5 },
+		frequency: 440.0,
+	},
+	Duration::from_millis(500),
+)
+.envelope(Envelope {
+	attack: Duration::from_millis(5),
+	decay: Duration::from_millis(200),
+	sustain: 0.0,
+	..Default::default()
+});
+let sound_data = StaticSoundData::generate(generator, StaticSoundSettings::new());
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct SignalGenerator {
+	/// The kind of signal to generate.
+	pub signal: Signal,
+	/// How long the generated audio should be.
+	pub duration: Duration,
+	/// The sample rate of the generated audio (in Hz).
+	pub sample_rate: u32,
+	/// An optional amplitude envelope applied to the signal.
+	pub envelope: Option<Envelope>,
+	/// The seed used when generating noise.
+	///
+	/// Generating noise with the same seed will always
+	/// produce the same audio.
+	pub seed: u64,
+}
+
+impl SignalGenerator {
+	/// Creates a new [`SignalGenerator`] with the default settings.
+	pub fn new(signal: Signal, duration: Duration) -> Self {
+		Self {
+			signal,
+			duration,
+			sample_rate: 48_000,
+			envelope: None,
+			seed: 1,
+		}
+	}
+
+	/// Sets the sample rate of the generated audio (in Hz).
+	pub fn sample_rate(self, sample_rate: u32) -> Self {
+		Self {
+			sample_rate,
+			..self
+		}
+	}
+
+	/// Sets the amplitude envelope applied to the signal.
+	pub fn envelope(self, envelope: impl Into<Option<Envelope>>) -> Self {
+		Self {
+			envelope: envelope.into(),
+			..self
+		}
+	}
+
+	/// Sets the seed used when generating noise.
+	pub fn seed(self, seed: u64) -> Self {
+		Self { seed, ..self }
+	}
+
+	/// Generates the frames of audio.
+	pub fn frames(&self) -> Vec<Frame> {
+		let num_frames = (self.duration.as_secs_f64() * self.sample_rate as f64).round() as usize;
+		let duration = num_frames as f64 / self.sample_rate as f64;
+		let mut samples: Vec<f64> = match self.signal {
+			Signal::Tone {
+				waveform,
+				frequency,
+			} => (0..num_frames)
+				.map(|i| {
+					let phase = (i as f64 * frequency / self.sample_rate as f64).fract();
+					waveform.value(phase)
+				})
+				.collect(),
+			Signal::WhiteNoise => {
+				let mut random = Random::new(self.seed);
+				(0..num_frames).map(|_| random.next_f64()).collect()
+			}
+			Signal::PinkNoise => {
+				let mut random = Random::new(self.seed);
+				let mut pink_noise_filter = PinkNoiseFilter::default();
+				let mut samples: Vec<f64> = (0..num_frames)
+					.map(|_| pink_noise_filter.process(random.next_f64()))
+					.collect();
+				// the filter's gain varies with the input, so normalize
+				// the result to use the full amplitude range
+				let peak = samples.iter().fold(0.0f64, |peak, x| peak.max(x.abs()));
+				if peak > 0.0 {
+					for sample in &mut samples {
+						*sample /= peak;
+					}
+				}
+				samples
+			}
+		};
+		if let Some(envelope) = self.envelope {
+			for (i, sample) in samples.iter_mut().enumerate() {
+				*sample *= envelope.amplitude(i as f64 / self.sample_rate as f64, duration);
+			}
+		}
+		samples
+			.into_iter()
+			.map(|sample| Frame::from_mono(sample as f32))
+			.collect()
+	}
+}
+
+impl StaticSoundData {
+	/// Creates a [`StaticSoundData`] from a generated signal.
+	pub fn generate(generator: SignalGenerator, settings: StaticSoundSettings) -> Self {
+		Self::from_frames(generator.sample_rate, generator.frames(), settings)
+	}
+}
+
+/// A xorshift* pseudo-random number generator.
+struct Random {
+	state: u64,
+}
+
+impl Random {
+	fn new(seed: u64) -> Self {
+		Self {
+			// xorshift generators get stuck at 0
+			state: if seed == 0 {
+				0x9E37_79B9_7F4A_7C15
+			} else {
+				seed
+			},
+		}
+	}
+
+	/// Returns a random number from `-1.0` to `1.0`.
+	fn next_f64(&mut self) -> f64 {
+		self.state ^= self.state >> 12;
+		self.state ^= self.state << 25;
+		self.state ^= self.state >> 27;
+		let value = self.state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+		(value >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+	}
+}
+
+// Paul Kellet's economy pink noise filter:
+// https://www.firstpr.com.au/dsp/pink-noise/
+#[derive(Default)]
+struct PinkNoiseFilter {
+	b: [f64; 3],
+}
+
+impl PinkNoiseFilter {
+	fn process(&mut self, white: f64) -> f64 {
+		self.b[0] = 0.99765 * self.b[0] + white * 0.0990460;
+		self.b[1] = 0.96300 * self.b[1] + white * 0.2965164;
+		self.b[2] = 0.57000 * self.b[2] + white * 1.0526913;
+		self.b[0] + self.b[1] + self.b[2] + white * 0.1848
+	}
+}
@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+use crate::{dsp::Frame, modulator::lfo::Waveform};
+
+use super::{Envelope, Signal, SignalGenerator};
+
+/// Tests that a generated tone has the expected length and shape.
+#[test]
+#[allow(clippy::float_cmp)]
+fn generates_tone() {
+	let frames = SignalGenerator::new(
+		Signal::Tone {
+			waveform: Waveform::Pulse { width: 0.5 },
+			frequency: 1.0,
+		},
+		Duration::from_secs(1),
+	)
+	.sample_rate(4)
+	.frames();
+	assert_eq!(
+		frames,
+		vec![
+			Frame::from_mono(1.0),
+			Frame::from_mono(1.0),
+			Frame::from_mono(-1.0),
+			Frame::from_mono(-1.0),
+		]
+	);
+}
+
+/// Tests that generated noise stays in range and is the same
+/// every time for the same seed.
+#[test]
+fn generates_deterministic_noise() {
+	for signal in [Signal::WhiteNoise, Signal::PinkNoise] {
+		let generator = SignalGenerator::new(signal, Duration::from_secs(1)).sample_rate(1000);
+		let frames = generator.frames();
+		assert_eq!(frames.len(), 1000);
+		assert!(frames
+			.iter()
+			.all(|frame| frame.left.abs() <= 1.0 && frame.left == frame.right));
+		assert!(frames.iter().any(|frame| frame.left != 0.0));
+		assert_eq!(frames, generator.frames());
+		assert_ne!(frames, generator.seed(2).frames());
+	}
+}
+
+/// Tests that the amplitude envelope is applied to the signal.
+#[test]
+fn applies_envelope() {
+	let frames = SignalGenerator::new(
+		Signal::Tone {
+			waveform: Waveform::Pulse { width: 1.0 },
+			frequency: 1.0,
+		},
+		Duration::from_secs(10),
+	)
+	.sample_rate(1)
+	.envelope(Envelope {
+		attack: Duration::from_secs(2),
+		decay: Duration::from_secs(2),
+		sustain: 0.5,
+		release: Duration::from_secs(2),
+	})
+	.frames();
+	let expected = [0.0, 0.5, 1.0, 0.75, 0.5, 0.5, 0.5, 0.5, 0.5, 0.25];
+	for (frame, expected) in frames.iter().zip(expected) {
+		assert!((frame.left - expected).abs() < 0.00001);
+	}
+}
+
+/// Tests that a release longer than the generated audio is shortened
+/// to start at the end of the attack instead of silencing the sound.
+#[test]
+fn shortens_release_longer_than_duration() {
+	let frames = SignalGenerator::new(
+		Signal::Tone {
+			waveform: Waveform::Pulse { width: 1.0 },
+			frequency: 1.0,
+		},
+		Duration::from_secs(5),
+	)
+	.sample_rate(1)
+	.envelope(Envelope {
+		attack: Duration::from_secs(1),
+		release: Duration::from_secs(10),
+		..Default::default()
+	})
+	.frames();
+	let expected = [0.0, 1.0, 0.75, 0.5, 0.25];
+	assert_eq!(frames.len(), expected.len());
+	for (frame, expected) in frames.iter().zip(expected) {
+		assert!((frame.left - expected).abs() < 0.00001);
+	}
+}
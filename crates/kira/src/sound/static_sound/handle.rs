@@ -27,6 +27,35 @@ impl StaticSoundHandle {
 		self.shared.position()
 	}
 
+	/// Returns how far through the sound playback is, from `0.0`
+	/// (the start) to `1.0` (the end), or `None` if the sound
+	/// has stopped.
+	///
+	/// For looping sounds, this is the progress through the current
+	/// iteration of the loop. Before the loop is entered, this is the
+	/// progress through the part of the sound that precedes the loop.
+	pub fn progress(&self) -> Option<f64> {
+		if self.state() == PlaybackState::Stopped {
+			return None;
+		}
+		Some(self.shared.progress())
+	}
+
+	/// Returns the amount of audio left to play (in seconds), or `None`
+	/// if the sound has stopped.
+	///
+	/// For looping sounds, this is the time left until playback next
+	/// reaches the start or end of the loop region.
+	///
+	/// This is measured in the sound's own timeline, so it does not
+	/// take the playback rate into account.
+	pub fn time_remaining(&self) -> Option<f64> {
+		if self.state() == PlaybackState::Stopped {
+			return None;
+		}
+		Some(self.shared.time_remaining())
+	}
+
 	/**
 	Sets the volume of the sound.
 
@@ -99,6 +128,22 @@ impl StaticSoundHandle {
 			.map_err(|_| CommandError::CommandQueueFull)
 	}
 
+	/// Stops any in-progress volume transition and holds the
+	/// current volume.
+	pub fn cancel_volume_tween(&mut self) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::CancelVolumeTween)
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
+	/// Stops any in-progress volume transition and immediately
+	/// sets the volume.
+	pub fn set_volume_immediate(&mut self, volume: impl Into<Volume>) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::SetVolumeImmediate(volume.into()))
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
 	/**
 	Sets the playback rate of the sound.
 
@@ -356,6 +401,53 @@ impl StaticSoundHandle {
 			.map_err(|_| CommandError::CommandQueueFull)
 	}
 
+	/**
+	Stops looping the sound, so that it plays through to the end
+	of its playback region and then stops.
+
+	This can be used to play instrument notes that are held for an
+	indefinite amount of time: set the loop region to the sustained
+	part of the note, and the audio after the loop region will be
+	played as a release tail once the sound is released. The sound stays
+	in the [`PlaybackState::Playing`] state while the tail plays, and
+	[`is_released`](StaticSoundHandle::is_released) will return `true`.
+	Once the sound is released, changes to its loop region are ignored.
+
+	To fade the sound out instead of playing a tail, use
+	[`stop`](StaticSoundHandle::stop).
+
+	# Examples
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::static_sound::{StaticSoundData, StaticSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	// the note's sustain is from 0.5 to 1.5 seconds, and the release
+	// tail is everything after 1.5 seconds
+	let mut note = manager.play(StaticSoundData::from_file(
+		"note.ogg",
+		StaticSoundSettings::new().loop_region(0.5..1.5),
+	)?)?;
+	// later, when the key is let go...
+	note.release()?;
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn release(&mut self) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Release)
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
+	/// Returns `true` if the sound has been [released](StaticSoundHandle::release)
+	/// and is playing through to the end.
+	pub fn is_released(&self) -> bool {
+		self.shared.is_released()
+	}
+
 	/// Sets the playback position to the specified time in seconds.
 	pub fn seek_to(&mut self, position: f64) -> Result<(), CommandError> {
 		self.command_producer
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
 	sound::{IntoOptionalRegion, PlaybackRate, Region},
 	tween::{Tween, Value},
@@ -10,10 +12,19 @@ use crate::{
 pub struct StaticSoundSettings {
 	/// When the sound should start playing.
 	pub start_time: StartTime,
+	/// How long to wait after the start time before the sound
+	/// starts playing.
+	///
+	/// The delay doesn't count down while the sound is paused.
+	pub start_delay: Duration,
 	/// The portion of the sound that should be played.
 	pub playback_region: Region,
 	/// The portion of the sound that should be looped.
 	pub loop_region: Option<Region>,
+	/// Whether the start and end points of the loop region should be
+	/// moved to the nearest zero crossings to avoid clicks when the
+	/// sound loops.
+	pub snap_loop_to_zero_crossings: bool,
 	/// Whether the sound should be played in reverse.
 	pub reverse: bool,
 	/// The volume of the sound.
@@ -37,9 +48,11 @@ impl StaticSoundSettings {
 	pub fn new() -> Self {
 		Self {
 			start_time: StartTime::default(),
+			start_delay: Duration::ZERO,
 			playback_region: Region::default(),
 			reverse: false,
 			loop_region: None,
+			snap_loop_to_zero_crossings: false,
 			volume: Value::Fixed(Volume::Amplitude(1.0)),
 			playback_rate: Value::Fixed(PlaybackRate::Factor(1.0)),
 			panning: Value::Fixed(0.5),
@@ -76,6 +89,52 @@ impl StaticSoundSettings {
 		}
 	}
 
+	/**
+	Sets how long to wait after the start time before the sound
+	starts playing.
+
+	Sounds start playing at the beginning of the next batch of audio
+	that's processed, so sounds played from gameplay code can't start
+	any more precisely than the backend's buffer size. The start delay
+	is measured from the beginning of that batch with sub-sample
+	precision, so layered copies of a sound played at the same time
+	with the same (or intentionally different) delays stay exactly
+	in phase with each other.
+
+	If the start time is a clock time, the delay is measured from when
+	the clock reaches that time, which is precise to the sample.
+
+	The delay is part of the sound's playback, so it pauses along with
+	the sound: if the sound is paused before the delay is over, the rest
+	of the delay is waited out after the sound is resumed.
+
+	# Examples
+
+	Layering two copies of a sound with a slight offset:
+
+	```no_run
+	use std::time::Duration;
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::static_sound::{StaticSoundData, StaticSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let sound = StaticSoundData::from_file("kick.ogg", StaticSoundSettings::new())?;
+	manager.play(sound.clone())?;
+	manager.play(sound.with_modified_settings(|settings| {
+		settings.start_delay(Duration::from_secs_f64(0.5 / 48_000.0))
+	}))?;
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn start_delay(self, start_delay: Duration) -> Self {
+		Self {
+			start_delay,
+			..self
+		}
+	}
+
 	/**
 	Sets the portion of the sound that should be played.
 
@@ -133,6 +192,35 @@ impl StaticSoundSettings {
 		}
 	}
 
+	/**
+	Sets whether the start and end points of the loop region should be
+	moved to the nearest zero crossings to avoid clicks when the
+	sound loops.
+
+	A zero crossing is a point where the left and right channels both
+	cross (or touch) zero. Each loop point is moved to the closest zero
+	crossing within 50ms. If there isn't one, the loop point is left
+	where it is.
+
+	Use [`StaticSoundData::loop_region_frames`](super::StaticSoundData::loop_region_frames)
+	to see where the loop points ended up.
+
+	# Examples
+
+	```
+	# use kira::sound::static_sound::StaticSoundSettings;
+	let settings = StaticSoundSettings::new()
+		.loop_region(2.0..4.0)
+		.snap_loop_to_zero_crossings(true);
+	```
+	*/
+	pub fn snap_loop_to_zero_crossings(self, snap_loop_to_zero_crossings: bool) -> Self {
+		Self {
+			snap_loop_to_zero_crossings,
+			..self
+		}
+	}
+
 	/**
 	Sets the volume of the sound.
 
@@ -263,6 +351,9 @@ impl StaticSoundSettings {
 	/**
 	Sets the destination that this sound should be routed to.
 
+	To route a single instance of a sound somewhere else, use
+	[`StaticSoundData::with_modified_settings`](super::StaticSoundData::with_modified_settings).
+
 	# Examples
 
 	Set the output destination of a sound to a mixer track:
@@ -6,7 +6,7 @@ mod test;
 use std::{
 	convert::TryInto,
 	sync::{
-		atomic::{AtomicU64, AtomicU8, Ordering},
+		atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
 		Arc,
 	},
 };
@@ -37,25 +37,33 @@ pub(super) struct StaticSound {
 	resampler: Resampler,
 	transport: Transport,
 	fractional_position: f64,
+	/// How much longer (in seconds) to wait before starting playback.
+	remaining_start_delay: f64,
 	volume: Parameter<Volume>,
 	playback_rate: Parameter<PlaybackRate>,
 	panning: Parameter,
 	volume_fade: Parameter<Volume>,
+	/// Whether the sound was paused by [`Sound::on_pause_all`] and
+	/// should be resumed by [`Sound::on_resume_all`].
+	paused_by_pause_all: bool,
+	/// Whether the sound has been released and is playing
+	/// its tail.
+	released: bool,
 	shared: Arc<Shared>,
 }
 
 impl StaticSound {
 	pub fn new(data: StaticSoundData, command_consumer: HeapConsumer<Command>) -> Self {
 		let settings = data.settings;
-		let transport = Transport::new(
+		let mut transport = Transport::new(
 			data.settings.playback_region,
 			data.settings.loop_region,
 			data.settings.reverse,
 			data.sample_rate,
 			data.frames.len(),
 		);
+		transport.loop_region = data.loop_region_frames();
 		let starting_frame_index = transport.position;
-		let position = starting_frame_index as f64 / data.sample_rate as f64;
 		let mut sound = Self {
 			command_consumer,
 			data,
@@ -68,15 +76,22 @@ impl StaticSound {
 			resampler: Resampler::new(starting_frame_index),
 			transport,
 			fractional_position: 0.0,
+			remaining_start_delay: settings.start_delay.as_secs_f64(),
 			volume: Parameter::new(settings.volume, Volume::Amplitude(1.0)),
 			playback_rate: Parameter::new(settings.playback_rate, PlaybackRate::Factor(1.0)),
 			panning: Parameter::new(settings.panning, 0.5),
 			volume_fade: create_volume_fade_parameter(settings.fade_in_tween),
+			paused_by_pause_all: false,
+			released: false,
 			shared: Arc::new(Shared {
 				state: AtomicU8::new(PlaybackState::Playing as u8),
-				position: AtomicU64::new(position.to_bits()),
+				position: AtomicU64::new(0.0f64.to_bits()),
+				progress: AtomicU64::new(0.0f64.to_bits()),
+				time_remaining: AtomicU64::new(0.0f64.to_bits()),
+				released: AtomicBool::new(false),
 			}),
 		};
+		sound.update_shared_position(starting_frame_index);
 		// fill the resample buffer with 3 samples so playback can
 		// start immediately
 		for _ in 0..3 {
@@ -95,6 +110,7 @@ impl StaticSound {
 	}
 
 	fn pause(&mut self, fade_out_tween: Tween) {
+		self.paused_by_pause_all = false;
 		self.set_state(PlaybackState::Pausing);
 		self.volume_fade.set(
 			Value::Fixed(Volume::Decibels(Volume::MIN_DECIBELS)),
@@ -103,12 +119,14 @@ impl StaticSound {
 	}
 
 	fn resume(&mut self, fade_in_tween: Tween) {
+		self.paused_by_pause_all = false;
 		self.set_state(PlaybackState::Playing);
 		self.volume_fade
 			.set(Value::Fixed(Volume::Decibels(0.0)), fade_in_tween);
 	}
 
 	fn stop(&mut self, fade_out_tween: Tween) {
+		self.paused_by_pause_all = false;
 		self.set_state(PlaybackState::Stopping);
 		self.volume_fade.set(
 			Value::Fixed(Volume::Decibels(Volume::MIN_DECIBELS)),
@@ -116,6 +134,12 @@ impl StaticSound {
 		);
 	}
 
+	fn release(&mut self) {
+		self.released = true;
+		self.shared.released.store(true, Ordering::SeqCst);
+		self.transport.loop_region = None;
+	}
+
 	fn is_playing_backwards(&self) -> bool {
 		let mut is_playing_backwards = self.playback_rate.value().as_factor().is_sign_negative();
 		if self.data.settings.reverse {
@@ -142,6 +166,16 @@ impl StaticSound {
 		}
 	}
 
+	/// Moves the playback position forward by the given number of frames
+	/// (which can be fractional).
+	fn advance_position(&mut self, frames: f64) {
+		self.fractional_position += frames;
+		while self.fractional_position >= 1.0 {
+			self.fractional_position -= 1.0;
+			self.update_position();
+		}
+	}
+
 	fn seek_to_index(&mut self, index: i64) {
 		self.transport.seek_to(index);
 		// if the sound is playing, push a frame to the resample buffer
@@ -174,6 +208,39 @@ impl StaticSound {
 		self.resampler.push_frame(frame, self.transport.position);
 	}
 
+	/// Reports the position, progress, and remaining time of the sound
+	/// at the given frame to the [`StaticSoundHandle`](super::StaticSoundHandle).
+	fn update_shared_position(&self, frame_index: i64) {
+		let sample_rate = self.data.sample_rate as f64;
+		let backwards = self.is_playing_backwards();
+		let (section_start, section_end) = self.transport.current_section(frame_index, backwards);
+		let (elapsed_frames, remaining_frames) = if backwards {
+			(
+				section_end - 1 - frame_index,
+				frame_index - section_start + 1,
+			)
+		} else {
+			(frame_index - section_start, section_end - frame_index)
+		};
+		let section_length = section_end - section_start;
+		let progress = if section_length > 0 {
+			(elapsed_frames as f64 / section_length as f64).clamp(0.0, 1.0)
+		} else {
+			1.0
+		};
+		self.shared.position.store(
+			(frame_index as f64 / sample_rate).to_bits(),
+			Ordering::SeqCst,
+		);
+		self.shared
+			.progress
+			.store(progress.to_bits(), Ordering::SeqCst);
+		self.shared.time_remaining.store(
+			(remaining_frames.max(0) as f64 / sample_rate).to_bits(),
+			Ordering::SeqCst,
+		);
+	}
+
 	fn seek_by(&mut self, amount: f64) {
 		let current_position = self.transport.position as f64 / self.data.sample_rate as f64;
 		let position = current_position + amount;
@@ -194,13 +261,12 @@ impl Sound for StaticSound {
 
 	fn on_start_processing(&mut self) {
 		let last_played_frame_position = self.resampler.current_frame_index();
-		self.shared.position.store(
-			(last_played_frame_position as f64 / self.data.sample_rate as f64).to_bits(),
-			Ordering::SeqCst,
-		);
+		self.update_shared_position(last_played_frame_position);
 		while let Some(command) = self.command_consumer.pop() {
 			match command {
 				Command::SetVolume(volume, tween) => self.volume.set(volume, tween),
+				Command::CancelVolumeTween => self.volume.cancel_tween(),
+				Command::SetVolumeImmediate(volume) => self.volume.set_immediate(volume),
 				Command::SetPlaybackRate(playback_rate, tween) => {
 					self.playback_rate.set(playback_rate, tween)
 				}
@@ -210,14 +276,24 @@ impl Sound for StaticSound {
 					self.data.sample_rate,
 					self.data.frames.len(),
 				),
-				Command::SetLoopRegion(loop_region) => self.transport.set_loop_region(
-					loop_region,
-					self.data.sample_rate,
-					self.data.frames.len(),
-				),
+				// a released sound plays through to the end, so it
+				// shouldn't start looping again
+				Command::SetLoopRegion(_) if self.released => {}
+				Command::SetLoopRegion(loop_region) => {
+					self.transport.set_loop_region(
+						loop_region,
+						self.data.sample_rate,
+						self.data.frames.len(),
+					);
+					self.transport.loop_region = self
+						.transport
+						.loop_region
+						.map(|loop_region| self.data.snap_loop_region(loop_region));
+				}
 				Command::Pause(tween) => self.pause(tween),
 				Command::Resume(tween) => self.resume(tween),
 				Command::Stop(tween) => self.stop(tween),
+				Command::Release => self.release(),
 				Command::SeekBy(amount) => {
 					self.seek_by(amount);
 				}
@@ -228,6 +304,19 @@ impl Sound for StaticSound {
 		}
 	}
 
+	fn on_pause_all(&mut self, fade_out_tween: Tween) {
+		if self.state == PlaybackState::Playing {
+			self.pause(fade_out_tween);
+			self.paused_by_pause_all = true;
+		}
+	}
+
+	fn on_resume_all(&mut self, fade_in_tween: Tween) {
+		if self.paused_by_pause_all {
+			self.resume(fade_in_tween);
+		}
+	}
+
 	fn process(
 		&mut self,
 		dt: f64,
@@ -276,14 +365,26 @@ impl Sound for StaticSound {
 			WhenToStart::Never => return Frame::ZERO,
 		}
 
+		let frames_per_second =
+			self.data.sample_rate as f64 * self.playback_rate.value().as_factor().abs();
+
+		// wait for the start delay to pass
+		if self.remaining_start_delay > 0.0 {
+			if self.state != PlaybackState::Paused {
+				self.remaining_start_delay -= dt;
+			}
+			return Frame::ZERO;
+		}
+		// if the start delay ended partway through the previous frame, skip
+		// the part of the audio that would have played since then
+		if self.remaining_start_delay < 0.0 {
+			self.advance_position(-self.remaining_start_delay * frames_per_second);
+			self.remaining_start_delay = 0.0;
+		}
+
 		// play back audio
 		let out = self.resampler.get(self.fractional_position as f32);
-		self.fractional_position +=
-			self.data.sample_rate as f64 * self.playback_rate.value().as_factor().abs() * dt;
-		while self.fractional_position >= 1.0 {
-			self.fractional_position -= 1.0;
-			self.update_position();
-		}
+		self.advance_position(frames_per_second * dt);
 		out
 	}
 
@@ -295,6 +396,9 @@ impl Sound for StaticSound {
 pub(super) struct Shared {
 	state: AtomicU8,
 	position: AtomicU64,
+	progress: AtomicU64,
+	time_remaining: AtomicU64,
+	released: AtomicBool,
 }
 
 impl Shared {
@@ -312,4 +416,16 @@ impl Shared {
 	pub fn position(&self) -> f64 {
 		f64::from_bits(self.position.load(Ordering::SeqCst))
 	}
+
+	pub fn progress(&self) -> f64 {
+		f64::from_bits(self.progress.load(Ordering::SeqCst))
+	}
+
+	pub fn time_remaining(&self) -> f64 {
+		f64::from_bits(self.time_remaining.load(Ordering::SeqCst))
+	}
+
+	pub fn is_released(&self) -> bool {
+		self.released.load(Ordering::SeqCst)
+	}
 }
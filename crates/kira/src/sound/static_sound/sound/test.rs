@@ -63,6 +63,42 @@ fn plays_all_samples() {
 	assert_eq!(sound.state, PlaybackState::Stopped);
 }
 
+/// Tests that playing a `StaticSoundData` shares its frames with
+/// the playing sound instead of copying them.
+#[test]
+fn shares_frames_with_sound_data() {
+	let data = StaticSoundData::from_frames(1, vec![Frame::from_mono(1.0); 4], Default::default());
+	let (sound_1, _) = data.clone().split();
+	let (sound_2, _) = data.clone().split();
+	assert!(Arc::ptr_eq(&sound_1.data.frames, &data.frames));
+	assert!(Arc::ptr_eq(&sound_2.data.frames, &data.frames));
+}
+
+/// Tests that `StaticSound`s with no frames or a single frame
+/// play without panicking and then finish.
+#[test]
+fn plays_empty_and_single_frame_sounds() {
+	for num_frames in [0, 1] {
+		let data = StaticSoundData {
+			sample_rate: 1,
+			frames: vec![Frame::from_mono(1.0); num_frames].into(),
+			settings: StaticSoundSettings::new(),
+		};
+		let (mut sound, _) = data.split();
+		let mut output = vec![];
+		for _ in 0..10 {
+			output.push(sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build(),
+			));
+		}
+		let num_non_silent_frames = output.iter().filter(|frame| **frame != Frame::ZERO).count();
+		assert_eq!(num_non_silent_frames, num_frames);
+		assert!(sound.finished());
+	}
+}
+
 /// Tests that a `StaticSound` correctly reports its playback state
 /// to be queried by StaticSoundHandle::state.
 #[test]
@@ -110,6 +146,79 @@ fn reports_playback_position() {
 	}
 }
 
+/// Tests that a `StaticSound` correctly reports its progress and
+/// remaining time to be queried by StaticSoundHandle.
+#[test]
+#[allow(clippy::float_cmp)]
+fn reports_progress_and_time_remaining() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(0.0); 10]),
+		settings: StaticSoundSettings::new(),
+	};
+	let (mut sound, handle) = data.split();
+
+	for i in 0..5 {
+		assert_eq!(handle.progress(), Some(i as f64 / 10.0));
+		assert_eq!(handle.time_remaining(), Some(10.0 - i as f64));
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+		sound.on_start_processing();
+	}
+
+	for _ in 0..10 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+		sound.on_start_processing();
+	}
+	assert_eq!(handle.progress(), None);
+	assert_eq!(handle.time_remaining(), None);
+}
+
+/// Tests that a looping `StaticSound` reports its progress and
+/// remaining time relative to the current iteration of the loop.
+#[test]
+#[allow(clippy::float_cmp)]
+fn reports_progress_and_time_remaining_when_looping() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(0.0); 10]),
+		settings: StaticSoundSettings::new().loop_region(3.0..6.0),
+	};
+	let (mut sound, handle) = data.split();
+
+	// the part of the sound before the loop is reported separately
+	for i in 0..3 {
+		assert_eq!(handle.progress(), Some(i as f64 / 3.0));
+		assert_eq!(handle.time_remaining(), Some(3.0 - i as f64));
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+		sound.on_start_processing();
+	}
+
+	for _ in 0..3 {
+		for i in 0..3 {
+			assert_eq!(handle.progress(), Some(i as f64 / 3.0));
+			assert_eq!(handle.time_remaining(), Some(3.0 - i as f64));
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build(),
+			);
+			sound.on_start_processing();
+		}
+	}
+}
+
 /// Tests that a `StaticSound` fades out fully before pausing
 /// and fades back in when resuming.
 #[test]
@@ -224,6 +333,40 @@ fn pauses_and_resumes_with_fades() {
 	}
 }
 
+/// Tests that a `StaticSound` is paused by `on_pause_all` and resumed
+/// by `on_resume_all`, but not resumed if it was paused by the user.
+#[test]
+fn pause_all_and_resume_all() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(1.0); 100]),
+		settings: StaticSoundSettings::new(),
+	};
+	let (mut sound, mut handle) = data.split();
+
+	sound.on_pause_all(Tween::default());
+	sound.process(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	assert_eq!(sound.state, PlaybackState::Paused);
+	sound.on_resume_all(Tween::default());
+	assert_eq!(sound.state, PlaybackState::Playing);
+
+	handle.pause(Tween::default()).unwrap();
+	sound.on_start_processing();
+	sound.on_pause_all(Tween::default());
+	sound.process(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	assert_eq!(sound.state, PlaybackState::Paused);
+	sound.on_resume_all(Tween::default());
+	assert_eq!(sound.state, PlaybackState::Paused);
+}
+
 /// Tests that a `StaticSound` stops and finishes after a fade-out.
 #[test]
 #[allow(clippy::float_cmp)]
@@ -611,6 +754,91 @@ fn negative_start_position() {
 	expect_frame_soon(Frame::from_mono(1.0), &mut sound);
 }
 
+/// Tests that a `StaticSound` waits for its start delay and then
+/// starts partway through a frame if the delay isn't a whole
+/// number of frames.
+#[test]
+fn start_delay() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: (0..10).map(|i| Frame::from_mono(i as f32)).collect(),
+		settings: StaticSoundSettings::new().start_delay(Duration::from_secs_f64(2.5)),
+	};
+	let (mut sound, _) = data.split();
+
+	for _ in 0..3 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::ZERO
+		);
+	}
+	sound.process(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	for expected in [1.5, 2.5, 3.5] {
+		let out = sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+		let expected = Frame::from_mono(expected).panned(0.5);
+		assert!((out.left - expected.left).abs() < 1.0e-5);
+		assert!((out.right - expected.right).abs() < 1.0e-5);
+	}
+}
+
+/// Tests that the start delay of a `StaticSound` doesn't count
+/// down while the sound is paused.
+#[test]
+fn start_delay_pauses_with_sound() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(1.0); 10]),
+		settings: StaticSoundSettings::new().start_delay(Duration::from_secs(3)),
+	};
+	let (mut sound, mut handle) = data.split();
+	let no_fade = Tween {
+		duration: Duration::ZERO,
+		..Default::default()
+	};
+
+	sound.process(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	handle.pause(no_fade).unwrap();
+	sound.on_start_processing();
+	for _ in 0..5 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	handle.resume(no_fade).unwrap();
+	sound.on_start_processing();
+
+	// two seconds of the delay should be left
+	for _ in 0..2 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::ZERO
+		);
+	}
+	expect_frame_soon(Frame::from_mono(1.0).panned(0.5), &mut sound);
+}
+
 /// Tests that starting a `StaticSound` past the end of the sound
 /// will not cause a panic.
 #[test]
@@ -686,6 +914,47 @@ fn loops_forward() {
 	);
 }
 
+/// Tests that a released `StaticSound` stops looping, plays
+/// the audio after the loop region, and then stops, even if
+/// a new loop region is set after it's released.
+#[test]
+fn release() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: (0..10).map(|i| Frame::from_mono(i as f32)).collect(),
+		settings: StaticSoundSettings::new().loop_region(3.0..6.0),
+	};
+	let (mut sound, mut handle) = data.split();
+
+	// the sound should loop until it's released
+	for _ in 0..20 {
+		let frame = sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+		assert!(frame.left < Frame::from_mono(6.0).panned(0.5).left);
+	}
+
+	assert!(!handle.is_released());
+	handle.release().unwrap();
+	handle.set_loop_region(3.0..6.0).unwrap();
+	sound.on_start_processing();
+	assert_eq!(handle.state(), PlaybackState::Playing);
+	assert!(handle.is_released());
+	expect_frame_soon(Frame::from_mono(9.0).panned(0.5), &mut sound);
+	for _ in 0..10 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	sound.on_start_processing();
+	assert_eq!(handle.state(), PlaybackState::Stopped);
+	assert!(sound.finished());
+}
+
 /// Tests that the volume of a `StaticSound` can be adjusted.
 #[test]
 #[allow(clippy::float_cmp)]
@@ -739,6 +1008,68 @@ fn set_volume() {
 	expect_frame_soon(Frame::from_mono(0.5).panned(0.5), &mut sound);
 }
 
+/// Tests that a volume tween on a `StaticSound` can be cancelled,
+/// and that the volume can be set immediately.
+#[test]
+#[allow(clippy::float_cmp)]
+fn cancel_volume_tween_and_set_volume_immediate() {
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(1.0); 100]),
+		settings: StaticSoundSettings::new(),
+	};
+	let (mut sound, mut handle) = data.split();
+
+	handle
+		.set_volume(
+			0.0,
+			Tween {
+				duration: Duration::from_secs(10),
+				..Default::default()
+			},
+		)
+		.unwrap();
+	sound.on_start_processing();
+	for _ in 0..3 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+
+	handle.cancel_volume_tween().unwrap();
+	sound.on_start_processing();
+	// let the frames that were already resampled play out
+	for _ in 0..4 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	let held_frame = sound.process(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	assert!(held_frame.left > 0.0 && held_frame.left < Frame::from_mono(1.0).panned(0.5).left);
+	for _ in 0..3 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			held_frame
+		);
+	}
+
+	handle.set_volume_immediate(0.25).unwrap();
+	sound.on_start_processing();
+	expect_frame_soon(Frame::from_mono(0.25).panned(0.5), &mut sound);
+}
+
 /// Tests that the panning of a `StaticSound` can be adjusted.
 #[test]
 #[allow(clippy::float_cmp)]
@@ -46,6 +46,8 @@ use super::{PlaybackRate, Region};
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub(crate) enum SoundCommand {
 	SetVolume(Value<Volume>, Tween),
+	CancelVolumeTween,
+	SetVolumeImmediate(Volume),
 	SetPlaybackRate(Value<PlaybackRate>, Tween),
 	SetPanning(Value<f64>, Tween),
 	Pause(Tween),
@@ -1,9 +1,12 @@
 #[cfg(test)]
 mod test;
 
-use std::{sync::Arc, time::Duration};
+use std::{mem::size_of, sync::Arc, time::Duration};
 
-use crate::sound::SoundData;
+use crate::{
+	dsp::Frame,
+	sound::{SoundData, SoundInfo},
+};
 use ringbuf::HeapRb;
 
 use super::sound::Shared;
@@ -11,7 +14,10 @@ use super::{StreamingSoundHandle, StreamingSoundSettings};
 
 use super::{
 	decoder::Decoder,
-	sound::{decode_scheduler::DecodeScheduler, StreamingSound},
+	sound::{
+		decode_scheduler::{DecodeScheduler, BUFFER_SIZE},
+		StreamingSound,
+	},
 };
 
 const COMMAND_BUFFER_CAPACITY: usize = 8;
@@ -49,6 +55,11 @@ impl<T: Send> StreamingSoundData<T> {
 #[cfg(feature = "symphonia")]
 impl StreamingSoundData<crate::sound::FromFileError> {
 	/// Creates a [`StreamingSoundData`] for an audio file.
+	///
+	/// Returns [`FromFileError::FormatNotEnabled`](crate::sound::FromFileError::FormatNotEnabled)
+	/// if the file extension belongs to an audio format whose feature is disabled, and
+	/// [`FromFileError::EmptyAudioData`](crate::sound::FromFileError::EmptyAudioData)
+	/// if the file doesn't contain any audio.
 	pub fn from_file(
 		path: impl AsRef<std::path::Path>,
 		settings: StreamingSoundSettings,
@@ -56,7 +67,9 @@ impl StreamingSoundData<crate::sound::FromFileError> {
 		use std::fs::File;
 
 		use super::symphonia::SymphoniaDecoder;
+		use crate::sound::symphonia::check_format_enabled;
 
+		check_format_enabled(path.as_ref())?;
 		Ok(Self::from_decoder(
 			SymphoniaDecoder::new(Box::new(File::open(path)?))?,
 			settings,
@@ -145,4 +158,15 @@ impl<Error: Send + 'static> SoundData for StreamingSoundData<Error> {
 		scheduler.start();
 		Ok((Box::new(sound), handle))
 	}
+
+	/// Streaming sounds only keep a small buffer of decoded audio
+	/// in memory, so the memory usage reported is the size of
+	/// that buffer.
+	fn info(&self) -> SoundInfo {
+		SoundInfo {
+			duration: Some(self.duration()),
+			sample_rate: Some(self.decoder.sample_rate()),
+			memory_usage: BUFFER_SIZE * size_of::<Frame>(),
+		}
+	}
 }
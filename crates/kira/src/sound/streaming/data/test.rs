@@ -5,6 +5,9 @@ use crate::{
 	sound::streaming::{mock::MockDecoder, StreamingSoundData},
 };
 
+#[cfg(feature = "wav")]
+use crate::sound::test_util::wav_file_data;
+
 #[test]
 fn duration() {
 	let sound = StreamingSoundData {
@@ -13,3 +16,17 @@ fn duration() {
 	};
 	assert_eq!(sound.duration(), Duration::from_secs(4));
 }
+
+/// Tests that streaming a file with no audio returns an error.
+#[test]
+#[cfg(feature = "wav")]
+fn rejects_empty_audio_files() {
+	let result = StreamingSoundData::from_cursor(
+		std::io::Cursor::new(wav_file_data(&[])),
+		Default::default(),
+	);
+	assert!(matches!(
+		result,
+		Err(crate::sound::FromFileError::EmptyAudioData)
+	));
+}
@@ -45,6 +45,9 @@ impl SymphoniaDecoder {
 			.ok_or(FromFileError::UnknownSampleRate)?
 			.try_into()
 			.expect("could not convert u64 into usize");
+		if num_frames == 0 {
+			return Err(FromFileError::EmptyAudioData);
+		}
 		let decoder = codecs.make(&default_track.codec_params, &Default::default())?;
 		let track_id = default_track.id;
 		Ok(Self {
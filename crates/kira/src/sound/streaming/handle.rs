@@ -100,6 +100,22 @@ impl<Error> StreamingSoundHandle<Error> {
 			.map_err(|_| CommandError::CommandQueueFull)
 	}
 
+	/// Stops any in-progress volume transition and holds the
+	/// current volume.
+	pub fn cancel_volume_tween(&mut self) -> Result<(), CommandError> {
+		self.sound_command_producer
+			.push(SoundCommand::CancelVolumeTween)
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
+	/// Stops any in-progress volume transition and immediately
+	/// sets the volume.
+	pub fn set_volume_immediate(&mut self, volume: impl Into<Volume>) -> Result<(), CommandError> {
+		self.sound_command_producer
+			.push(SoundCommand::SetVolumeImmediate(volume.into()))
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
 	/**
 	Sets the playback rate of the sound.
 
@@ -71,6 +71,9 @@ pub(crate) struct StreamingSound {
 	volume: Parameter<Volume>,
 	playback_rate: Parameter<PlaybackRate>,
 	panning: Parameter,
+	/// Whether the sound was paused by [`Sound::on_pause_all`] and
+	/// should be resumed by [`Sound::on_resume_all`].
+	paused_by_pause_all: bool,
 	shared: Arc<Shared>,
 }
 
@@ -106,6 +109,7 @@ impl StreamingSound {
 			volume: Parameter::new(settings.volume, Volume::Amplitude(1.0)),
 			playback_rate: Parameter::new(settings.playback_rate, PlaybackRate::Factor(1.0)),
 			panning: Parameter::new(settings.panning, 0.5),
+			paused_by_pause_all: false,
 			shared,
 		}
 	}
@@ -143,18 +147,21 @@ impl StreamingSound {
 	}
 
 	fn pause(&mut self, tween: Tween) {
+		self.paused_by_pause_all = false;
 		self.set_state(PlaybackState::Pausing);
 		self.volume_fade
 			.set(Value::Fixed(Volume::Decibels(Volume::MIN_DECIBELS)), tween);
 	}
 
 	fn resume(&mut self, tween: Tween) {
+		self.paused_by_pause_all = false;
 		self.set_state(PlaybackState::Playing);
 		self.volume_fade
 			.set(Value::Fixed(Volume::Decibels(0.0)), tween);
 	}
 
 	fn stop(&mut self, tween: Tween) {
+		self.paused_by_pause_all = false;
 		self.set_state(PlaybackState::Stopping);
 		self.volume_fade
 			.set(Value::Fixed(Volume::Decibels(Volume::MIN_DECIBELS)), tween);
@@ -174,6 +181,8 @@ impl Sound for StreamingSound {
 		while let Some(command) = self.command_consumer.pop() {
 			match command {
 				SoundCommand::SetVolume(volume, tween) => self.volume.set(volume, tween),
+				SoundCommand::CancelVolumeTween => self.volume.cancel_tween(),
+				SoundCommand::SetVolumeImmediate(volume) => self.volume.set_immediate(volume),
 				SoundCommand::SetPlaybackRate(playback_rate, tween) => {
 					self.playback_rate.set(playback_rate, tween)
 				}
@@ -185,6 +194,19 @@ impl Sound for StreamingSound {
 		}
 	}
 
+	fn on_pause_all(&mut self, fade_out_tween: Tween) {
+		if self.state == PlaybackState::Playing {
+			self.pause(fade_out_tween);
+			self.paused_by_pause_all = true;
+		}
+	}
+
+	fn on_resume_all(&mut self, fade_in_tween: Tween) {
+		if self.paused_by_pause_all {
+			self.resume(fade_in_tween);
+		}
+	}
+
 	fn process(
 		&mut self,
 		dt: f64,
@@ -16,7 +16,7 @@ use ringbuf::{HeapConsumer, HeapProducer, HeapRb};
 
 use super::{Shared, TimestampedFrame};
 
-const BUFFER_SIZE: usize = 16_384;
+pub(crate) const BUFFER_SIZE: usize = 16_384;
 const DECODER_THREAD_SLEEP_DURATION: Duration = Duration::from_millis(1);
 
 pub(crate) enum NextStep {
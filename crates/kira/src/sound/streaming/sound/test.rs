@@ -797,6 +797,68 @@ fn set_volume() {
 	expect_frame_soon(Frame::from_mono(0.5).panned(0.5), &mut sound);
 }
 
+/// Tests that a volume tween on a `StreamingSound` can be cancelled,
+/// and that the volume can be set immediately.
+#[test]
+#[allow(clippy::float_cmp)]
+fn cancel_volume_tween_and_set_volume_immediate() {
+	let data = StreamingSoundData {
+		decoder: Box::new(MockDecoder::new(vec![Frame::from_mono(1.0); 100])),
+		settings: StreamingSoundSettings::new(),
+	};
+	let (mut sound, mut handle, mut scheduler) = data.split().unwrap();
+	while matches!(scheduler.run().unwrap(), NextStep::Continue) {}
+
+	handle
+		.set_volume(
+			0.0,
+			Tween {
+				duration: Duration::from_secs(10),
+				..Default::default()
+			},
+		)
+		.unwrap();
+	sound.on_start_processing();
+	for _ in 0..3 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+
+	handle.cancel_volume_tween().unwrap();
+	sound.on_start_processing();
+	// let the frames that were already resampled play out
+	for _ in 0..4 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	let held_frame = sound.process(
+		1.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+	assert!(held_frame.left > 0.0 && held_frame.left < Frame::from_mono(1.0).panned(0.5).left);
+	for _ in 0..3 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			held_frame
+		);
+	}
+
+	handle.set_volume_immediate(0.25).unwrap();
+	sound.on_start_processing();
+	expect_frame_soon(Frame::from_mono(0.25).panned(0.5), &mut sound);
+}
+
 /// Tests that the panning of a `StreamingSound` can be adjusted.
 #[test]
 #[allow(clippy::float_cmp)]
@@ -1,3 +1,6 @@
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod test;
+
 use symphonia::core::{
 	audio::{AudioBuffer, AudioBufferRef, Signal},
 	conv::{FromSample, IntoSample},
@@ -8,6 +11,31 @@ use crate::dsp::Frame;
 
 use super::FromFileError;
 
+/// Returns an error if the file extension of `path` belongs to an audio
+/// format whose Cargo feature is disabled.
+///
+/// Unrecognized extensions are allowed through so Symphonia can try to
+/// detect the format from the file contents.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn check_format_enabled(path: &std::path::Path) -> Result<(), FromFileError> {
+	let extension = match path.extension().and_then(|extension| extension.to_str()) {
+		Some(extension) => extension.to_lowercase(),
+		None => return Ok(()),
+	};
+	let (feature, enabled) = match extension.as_str() {
+		"mp3" => ("mp3", cfg!(feature = "mp3")),
+		"ogg" | "oga" => ("ogg", cfg!(feature = "ogg")),
+		"flac" => ("flac", cfg!(feature = "flac")),
+		"wav" | "wave" => ("wav", cfg!(feature = "wav")),
+		_ => return Ok(()),
+	};
+	if enabled {
+		Ok(())
+	} else {
+		Err(FromFileError::FormatNotEnabled(feature))
+	}
+}
+
 pub fn load_frames_from_buffer_ref(buffer: &AudioBufferRef) -> Result<Vec<Frame>, FromFileError> {
 	match buffer {
 		AudioBufferRef::U8(buffer) => load_frames_from_buffer(buffer),
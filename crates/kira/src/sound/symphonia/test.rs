@@ -0,0 +1,31 @@
+use std::path::Path;
+
+use crate::sound::FromFileError;
+
+use super::check_format_enabled;
+
+/// Tests that audio files are rejected if support for their
+/// format is not enabled.
+#[test]
+fn rejects_disabled_formats() {
+	for (path, feature, enabled) in [
+		("sound.mp3", "mp3", cfg!(feature = "mp3")),
+		("sound.ogg", "ogg", cfg!(feature = "ogg")),
+		("sound.FLAC", "flac", cfg!(feature = "flac")),
+		("sound.wav", "wav", cfg!(feature = "wav")),
+	] {
+		let result = check_format_enabled(Path::new(path));
+		if enabled {
+			assert!(result.is_ok());
+		} else {
+			assert!(matches!(result, Err(FromFileError::FormatNotEnabled(f)) if f == feature));
+		}
+	}
+}
+
+/// Tests that files with unrecognized extensions are allowed through.
+#[test]
+fn allows_unknown_formats() {
+	assert!(check_format_enabled(Path::new("sound.xyz")).is_ok());
+	assert!(check_format_enabled(Path::new("sound")).is_ok());
+}
@@ -0,0 +1,21 @@
+/// Creates the data for a mono 16-bit WAV file with the given samples.
+pub(crate) fn wav_file_data(samples: &[i16]) -> Vec<u8> {
+	let data_size = (samples.len() * 2) as u32;
+	let mut data = vec![];
+	data.extend_from_slice(b"RIFF");
+	data.extend_from_slice(&(36 + data_size).to_le_bytes());
+	data.extend_from_slice(b"WAVEfmt ");
+	data.extend_from_slice(&16u32.to_le_bytes());
+	data.extend_from_slice(&1u16.to_le_bytes());
+	data.extend_from_slice(&1u16.to_le_bytes());
+	data.extend_from_slice(&44_100u32.to_le_bytes());
+	data.extend_from_slice(&88_200u32.to_le_bytes());
+	data.extend_from_slice(&2u16.to_le_bytes());
+	data.extend_from_slice(&16u16.to_le_bytes());
+	data.extend_from_slice(b"data");
+	data.extend_from_slice(&data_size.to_le_bytes());
+	for sample in samples {
+		data.extend_from_slice(&sample.to_le_bytes());
+	}
+	data
+}
@@ -26,22 +26,12 @@ impl Transport {
 	) -> Self {
 		let playback_start = playback_region.start.into_samples(sample_rate);
 		let playback_end = match playback_region.end {
-			EndPosition::EndOfAudio => (num_frames - 1)
-				.try_into()
-				.expect("could not convert usize to i64"),
+			EndPosition::EndOfAudio => last_frame_index(num_frames),
 			EndPosition::Custom(end_position) => end_position.into_samples(sample_rate),
 		};
 		let playback_region = (playback_start, playback_end);
-		let loop_region = loop_region.map(|loop_region| {
-			let loop_start = loop_region.start.into_samples(sample_rate);
-			let loop_end = match loop_region.end {
-				EndPosition::EndOfAudio => num_frames
-					.try_into()
-					.expect("could not convert usize to i64"),
-				EndPosition::Custom(end_position) => end_position.into_samples(sample_rate),
-			};
-			(loop_start, loop_end)
-		});
+		let loop_region =
+			loop_region.map(|loop_region| loop_region_frames(loop_region, sample_rate, num_frames));
 		Self {
 			position: if reverse {
 				playback_region.1
@@ -62,9 +52,7 @@ impl Transport {
 	) {
 		let playback_start = playback_region.start.into_samples(sample_rate);
 		let playback_end = match playback_region.end {
-			EndPosition::EndOfAudio => (num_frames - 1)
-				.try_into()
-				.expect("could not convert usize to i64"),
+			EndPosition::EndOfAudio => last_frame_index(num_frames),
 			EndPosition::Custom(end_position) => end_position.into_samples(sample_rate),
 		};
 		self.playback_region = (playback_start, playback_end);
@@ -76,16 +64,8 @@ impl Transport {
 		sample_rate: u32,
 		num_frames: usize,
 	) {
-		self.loop_region = loop_region.map(|loop_region| {
-			let loop_start = loop_region.start.into_samples(sample_rate);
-			let loop_end = match loop_region.end {
-				EndPosition::EndOfAudio => num_frames
-					.try_into()
-					.expect("could not convert usize to i64"),
-				EndPosition::Custom(end_position) => end_position.into_samples(sample_rate),
-			};
-			(loop_start, loop_end)
-		});
+		self.loop_region =
+			loop_region.map(|loop_region| loop_region_frames(loop_region, sample_rate, num_frames));
 	}
 
 	pub fn increment_position(&mut self) {
@@ -112,6 +92,27 @@ impl Transport {
 		}
 	}
 
+	/// Returns the start and end frames (with an *exclusive* upper bound)
+	/// of the section of the sound that `position` is in. For looping
+	/// sounds, this is the current iteration of the loop, or the part of
+	/// the sound that plays before the loop is entered.
+	pub fn current_section(&self, position: i64, backwards: bool) -> (i64, i64) {
+		let playback_start = self.playback_region.0;
+		let playback_end = self.playback_region.1 + 1;
+		match self.loop_region {
+			Some((loop_start, loop_end)) => {
+				if backwards && position >= loop_end {
+					(loop_end, playback_end)
+				} else if !backwards && position < loop_start {
+					(playback_start, loop_start)
+				} else {
+					(loop_start, loop_end)
+				}
+			}
+			None => (playback_start, playback_end),
+		}
+	}
+
 	pub fn seek_to(&mut self, mut position: i64) {
 		if let Some((loop_start, loop_end)) = self.loop_region {
 			if position > self.position {
@@ -130,3 +131,25 @@ impl Transport {
 		}
 	}
 }
+
+/// Returns the index of the last frame of audio, which is `-1`
+/// if there's no audio.
+fn last_frame_index(num_frames: usize) -> i64 {
+	let num_frames: i64 = num_frames
+		.try_into()
+		.expect("could not convert usize to i64");
+	num_frames - 1
+}
+
+/// Converts a loop region to start and end frames. The upper bound
+/// is *exclusive*.
+pub fn loop_region_frames(loop_region: Region, sample_rate: u32, num_frames: usize) -> (i64, i64) {
+	let loop_start = loop_region.start.into_samples(sample_rate);
+	let loop_end = match loop_region.end {
+		EndPosition::EndOfAudio => num_frames
+			.try_into()
+			.expect("could not convert usize to i64"),
+		EndPosition::Custom(end_position) => end_position.into_samples(sample_rate),
+	};
+	(loop_start, loop_end)
+}
@@ -1,3 +1,5 @@
+use crate::sound::Region;
+
 use super::Transport;
 
 #[test]
@@ -125,3 +127,17 @@ fn seek_out_of_bounds() {
 	transport.seek_to(11);
 	assert!(!transport.playing);
 }
+
+#[test]
+fn handles_empty_audio() {
+	for reverse in [false, true] {
+		let mut transport = Transport::new(Region::default(), None, reverse, 1, 0);
+		assert_eq!(transport.playback_region, (0, -1));
+		if reverse {
+			transport.decrement_position();
+		} else {
+			transport.increment_position();
+		}
+		assert!(!transport.playing);
+	}
+}
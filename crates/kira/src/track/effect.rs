@@ -14,6 +14,8 @@ pub mod delay;
 pub mod distortion;
 pub mod eq_filter;
 pub mod filter;
+pub mod limiter;
+pub mod loudness_meter;
 pub mod panning_control;
 pub mod reverb;
 pub mod volume_control;
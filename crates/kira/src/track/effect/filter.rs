@@ -3,6 +3,9 @@
 mod builder;
 mod handle;
 
+#[cfg(test)]
+mod test;
+
 pub use builder::*;
 pub use handle::*;
 use ringbuf::HeapConsumer;
@@ -54,12 +57,19 @@ struct Filter {
 impl Filter {
 	/// Creates a new filter.
 	fn new(builder: FilterBuilder, command_consumer: HeapConsumer<Command>) -> Self {
+		let parameter = |value, default_value| {
+			let parameter = Parameter::new(value, default_value);
+			match builder.smoothing {
+				Some(time_constant) => parameter.with_smoothing(time_constant),
+				None => parameter,
+			}
+		};
 		Self {
 			command_consumer,
 			mode: builder.mode,
-			cutoff: Parameter::new(builder.cutoff, 1000.0),
-			resonance: Parameter::new(builder.resonance, 0.0),
-			mix: Parameter::new(builder.mix, 1.0),
+			cutoff: parameter(builder.cutoff, 1000.0),
+			resonance: parameter(builder.resonance, 0.0),
+			mix: parameter(builder.mix, 1.0),
 			ic1eq: Frame::ZERO,
 			ic2eq: Frame::ZERO,
 		}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use ringbuf::HeapRb;
 
 use crate::{
@@ -27,6 +29,10 @@ pub struct FilterBuilder {
 	/// only the dry signal will be heard. `1.0` means
 	/// only the wet signal will be heard.
 	pub mix: Value<f64>,
+	/// If set, changes to the cutoff, resonance, and mix are smoothed
+	/// out with this time constant, which helps avoid clicks and
+	/// "zipper" noise when they're changed quickly.
+	pub smoothing: Option<Duration>,
 }
 
 impl FilterBuilder {
@@ -66,6 +72,18 @@ impl FilterBuilder {
 			..self
 		}
 	}
+
+	/// Smooths out changes to the cutoff, resonance, and mix with the
+	/// given time constant.
+	///
+	/// See [`Parameter::with_smoothing`](crate::tween::Parameter::with_smoothing)
+	/// for more information.
+	pub fn smoothing(self, time_constant: Duration) -> Self {
+		Self {
+			smoothing: Some(time_constant),
+			..self
+		}
+	}
 }
 
 impl Default for FilterBuilder {
@@ -75,6 +93,7 @@ impl Default for FilterBuilder {
 			cutoff: Value::Fixed(1000.0),
 			resonance: Value::Fixed(0.0),
 			mix: Value::Fixed(1.0),
+			smoothing: None,
 		}
 	}
 }
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use ringbuf::HeapRb;
+
+use crate::{
+	clock::clock_info::MockClockInfoProviderBuilder, dsp::Frame,
+	modulator::value_provider::MockModulatorValueProviderBuilder, track::Effect, tween::Tween,
+};
+
+use super::{Filter, FilterBuilder, FilterHandle};
+
+/// Tests that a filter built with smoothing glides to a new cutoff
+/// instead of jumping to it.
+#[test]
+#[allow(clippy::float_cmp)]
+fn smoothing() {
+	let (mut smoothed, mut smoothed_handle) =
+		build(FilterBuilder::new().smoothing(Duration::from_millis(10)));
+	let (mut unsmoothed, mut unsmoothed_handle) = build(FilterBuilder::new());
+	smoothed_handle
+		.set_cutoff(
+			100.0,
+			Tween {
+				duration: Duration::ZERO,
+				..Default::default()
+			},
+		)
+		.unwrap();
+	unsmoothed_handle
+		.set_cutoff(
+			100.0,
+			Tween {
+				duration: Duration::ZERO,
+				..Default::default()
+			},
+		)
+		.unwrap();
+	process(&mut smoothed, 0.01);
+	process(&mut unsmoothed, 0.01);
+	assert_eq!(unsmoothed.cutoff.value(), 100.0);
+	// after one time constant, the value should be about 63% of the way
+	// to the target
+	let expected = 1000.0 - 900.0 * (1.0 - (-1.0f64).exp());
+	assert!((smoothed.cutoff.value() - expected).abs() < 1.0);
+	for _ in 0..20 {
+		process(&mut smoothed, 0.01);
+	}
+	assert!((smoothed.cutoff.value() - 100.0).abs() < 0.001);
+}
+
+fn build(builder: FilterBuilder) -> (Filter, FilterHandle) {
+	let (command_producer, command_consumer) = HeapRb::new(8).split();
+	(
+		Filter::new(builder, command_consumer),
+		FilterHandle { command_producer },
+	)
+}
+
+fn process(filter: &mut Filter, dt: f64) {
+	filter.on_start_processing();
+	filter.process(
+		Frame::ZERO,
+		dt,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	);
+}
@@ -0,0 +1,249 @@
+/*!
+Prevents audio from getting louder than a threshold.
+
+The limiter delays the audio passing through it by a short look-ahead
+time, which lets it start turning the volume down before a peak arrives
+instead of after. This keeps transients from slipping through without
+the distortion of hard clipping.
+
+To limit the final mix, add the limiter to the main track using
+[`AudioManagerSettings::main_track_builder`](crate::manager::AudioManagerSettings::main_track_builder).
+Because of the look-ahead, audio passing through the limiter is delayed.
+[`LimiterHandle::latency`] reports how long the delay is so it can be
+compensated for.
+
+# Examples
+
+```no_run
+use kira::{
+	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	track::{TrackBuilder, effect::limiter::LimiterBuilder},
+};
+
+let mut main_track_builder = TrackBuilder::new();
+let limiter = main_track_builder.add_effect(LimiterBuilder::new().threshold(-1.0));
+let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings {
+	main_track_builder,
+	..Default::default()
+})?;
+println!("latency: {:?}", limiter.latency());
+# Result::<(), Box<dyn std::error::Error>>::Ok(())
+```
+*/
+
+mod builder;
+mod handle;
+
+#[cfg(test)]
+mod test;
+
+pub use builder::*;
+pub use handle::*;
+
+use std::{
+	collections::VecDeque,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use ringbuf::HeapConsumer;
+
+use crate::{
+	clock::clock_info::ClockInfoProvider,
+	dsp::Frame,
+	modulator::value_provider::ModulatorValueProvider,
+	tween::{Parameter, Tween, Value},
+};
+
+use super::Effect;
+
+enum Command {
+	SetThreshold(Value<f64>, Tween),
+	SetReleaseDuration(Value<Duration>, Tween),
+}
+
+struct Limiter {
+	command_consumer: HeapConsumer<Command>,
+	shared: Arc<Shared>,
+	threshold: Parameter,
+	release_duration: Parameter<Duration>,
+	lookahead_duration: Duration,
+	/// The audio that hasn't been output yet.
+	delay_buffer: Vec<Frame>,
+	delay_position: usize,
+	/// The gains needed to keep the most recent input frames under
+	/// the threshold, along with the index of the frame each gain
+	/// belongs to. Gains that can never be the lowest in the window
+	/// again are discarded, so the gains are always in increasing order
+	/// and the first one is the lowest.
+	required_gains: VecDeque<(u64, f64)>,
+	frame_index: u64,
+	/// The most recent values of the gain envelope, which are averaged
+	/// to smoothly ramp into gain reduction.
+	envelope_history: Vec<f64>,
+	envelope_history_sum: f64,
+	gain_position: usize,
+	envelope: f64,
+}
+
+impl Limiter {
+	fn new(builder: LimiterBuilder, command_consumer: HeapConsumer<Command>) -> Self {
+		Self {
+			command_consumer,
+			shared: Arc::new(Shared::new(builder.lookahead_duration)),
+			threshold: Parameter::new(builder.threshold, LimiterBuilder::DEFAULT_THRESHOLD),
+			release_duration: Parameter::new(
+				builder.release_duration,
+				LimiterBuilder::DEFAULT_RELEASE_DURATION,
+			),
+			lookahead_duration: builder.lookahead_duration,
+			delay_buffer: vec![],
+			delay_position: 0,
+			required_gains: VecDeque::new(),
+			frame_index: 0,
+			envelope_history: vec![],
+			envelope_history_sum: 0.0,
+			gain_position: 0,
+			envelope: 1.0,
+		}
+	}
+
+	fn shared(&self) -> Arc<Shared> {
+		self.shared.clone()
+	}
+
+	fn init_buffers(&mut self, sample_rate: u32) {
+		let lookahead_frames =
+			(self.lookahead_duration.as_secs_f64() * sample_rate as f64).round() as usize;
+		// the gain envelope covers one more frame than the delay so that
+		// it has fully ramped down by the time a peak is output
+		self.delay_buffer = vec![Frame::ZERO; lookahead_frames];
+		self.delay_position = 0;
+		// allocate space for every frame in the window up front so the
+		// audio thread never has to
+		self.required_gains = VecDeque::with_capacity(lookahead_frames + 1);
+		self.frame_index = 0;
+		self.envelope_history = vec![1.0; lookahead_frames + 1];
+		self.envelope_history_sum = (lookahead_frames + 1) as f64;
+		self.gain_position = 0;
+		self.envelope = 1.0;
+		self.shared
+			.set_latency(lookahead_frames as f64 / sample_rate as f64);
+	}
+}
+
+impl Effect for Limiter {
+	fn init(&mut self, sample_rate: u32) {
+		self.init_buffers(sample_rate);
+	}
+
+	fn on_change_sample_rate(&mut self, sample_rate: u32) {
+		self.init_buffers(sample_rate);
+	}
+
+	fn on_start_processing(&mut self) {
+		while let Some(command) = self.command_consumer.pop() {
+			match command {
+				Command::SetThreshold(threshold, tween) => self.threshold.set(threshold, tween),
+				Command::SetReleaseDuration(release_duration, tween) => {
+					self.release_duration.set(release_duration, tween)
+				}
+			}
+		}
+	}
+
+	fn process(
+		&mut self,
+		input: Frame,
+		dt: f64,
+		clock_info_provider: &ClockInfoProvider,
+		modulator_value_provider: &ModulatorValueProvider,
+	) -> Frame {
+		self.threshold
+			.update(dt, clock_info_provider, modulator_value_provider);
+		self.release_duration
+			.update(dt, clock_info_provider, modulator_value_provider);
+
+		// the limiter hasn't been initialized yet
+		if self.envelope_history.is_empty() {
+			return input;
+		}
+
+		// calculate how much the input needs to be turned down
+		let threshold = 10.0f64.powf(self.threshold.value() / 20.0);
+		let peak = input.left.abs().max(input.right.abs()) as f64;
+		let required_gain = if peak > threshold {
+			threshold / peak
+		} else {
+			1.0
+		};
+		let window_len = self.envelope_history.len() as u64;
+		while matches!(self.required_gains.back(), Some((_, gain)) if *gain >= required_gain) {
+			self.required_gains.pop_back();
+		}
+		while matches!(self.required_gains.front(), Some((index, _)) if index + window_len <= self.frame_index)
+		{
+			self.required_gains.pop_front();
+		}
+		self.required_gains
+			.push_back((self.frame_index, required_gain));
+		self.frame_index += 1;
+
+		// hold the lowest gain needed by any of the frames that are
+		// waiting to be output, and relax smoothly after that
+		let held_gain = self.required_gains.front().map_or(1.0, |(_, gain)| *gain);
+		if held_gain < self.envelope {
+			self.envelope = held_gain;
+		} else {
+			let release_duration = self.release_duration.value().as_secs_f64();
+			let speed = (-1.0 / (release_duration / dt)).exp();
+			self.envelope = held_gain + speed * (self.envelope - held_gain);
+		}
+
+		// average the envelope over the look-ahead window so the gain
+		// reduction ramps in instead of jumping
+		self.envelope_history_sum += self.envelope - self.envelope_history[self.gain_position];
+		self.envelope_history[self.gain_position] = self.envelope;
+		let gain = (self.envelope_history_sum / self.envelope_history.len() as f64).min(1.0);
+		self.gain_position = (self.gain_position + 1) % self.envelope_history.len();
+		// recalculate the sum every once in a while so rounding errors
+		// don't accumulate
+		if self.gain_position == 0 {
+			self.envelope_history_sum = self.envelope_history.iter().sum();
+		}
+
+		// delay the input by the look-ahead time
+		let output = if self.delay_buffer.is_empty() {
+			input
+		} else {
+			let output = self.delay_buffer[self.delay_position];
+			self.delay_buffer[self.delay_position] = input;
+			self.delay_position = (self.delay_position + 1) % self.delay_buffer.len();
+			output
+		};
+		output * gain as f32
+	}
+}
+
+struct Shared {
+	latency: AtomicU64,
+}
+
+impl Shared {
+	fn new(lookahead_duration: Duration) -> Self {
+		Self {
+			latency: AtomicU64::new(lookahead_duration.as_secs_f64().to_bits()),
+		}
+	}
+
+	fn latency(&self) -> Duration {
+		Duration::from_secs_f64(f64::from_bits(self.latency.load(Ordering::SeqCst)))
+	}
+
+	fn set_latency(&self, latency: f64) {
+		self.latency.store(latency.to_bits(), Ordering::SeqCst);
+	}
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use ringbuf::HeapRb;
+
+use crate::{
+	track::effect::{Effect, EffectBuilder},
+	tween::Value,
+};
+
+use super::{Limiter, LimiterHandle};
+
+const COMMAND_CAPACITY: usize = 8;
+
+/// Configures a limiter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct LimiterBuilder {
+	/// The maximum volume of the output (in dBFS).
+	pub threshold: Value<f64>,
+	/// How much time it takes for the volume to return to normal
+	/// after a peak has passed.
+	pub release_duration: Value<Duration>,
+	/// How far ahead the limiter looks for peaks.
+	///
+	/// Longer look-ahead times allow the limiter to turn the volume
+	/// down more gradually, but delay the audio by a larger amount.
+	pub lookahead_duration: Duration,
+}
+
+impl LimiterBuilder {
+	pub(crate) const DEFAULT_THRESHOLD: f64 = 0.0;
+	pub(crate) const DEFAULT_RELEASE_DURATION: Duration = Duration::from_millis(100);
+	pub(crate) const DEFAULT_LOOKAHEAD_DURATION: Duration = Duration::from_millis(5);
+
+	/// Creates a new [`LimiterBuilder`] with the default settings.
+	pub fn new() -> Self {
+		Self {
+			threshold: Value::Fixed(Self::DEFAULT_THRESHOLD),
+			release_duration: Value::Fixed(Self::DEFAULT_RELEASE_DURATION),
+			lookahead_duration: Self::DEFAULT_LOOKAHEAD_DURATION,
+		}
+	}
+
+	/// Sets the maximum volume of the output (in dBFS).
+	pub fn threshold(self, threshold: impl Into<Value<f64>>) -> Self {
+		Self {
+			threshold: threshold.into(),
+			..self
+		}
+	}
+
+	/// Sets how much time it takes for the volume to return to normal
+	/// after a peak has passed.
+	pub fn release_duration(self, release_duration: impl Into<Value<Duration>>) -> Self {
+		Self {
+			release_duration: release_duration.into(),
+			..self
+		}
+	}
+
+	/// Sets how far ahead the limiter looks for peaks.
+	///
+	/// Longer look-ahead times allow the limiter to turn the volume
+	/// down more gradually, but delay the audio by a larger amount.
+	pub fn lookahead_duration(self, lookahead_duration: Duration) -> Self {
+		Self {
+			lookahead_duration,
+			..self
+		}
+	}
+}
+
+impl Default for LimiterBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl EffectBuilder for LimiterBuilder {
+	type Handle = LimiterHandle;
+
+	fn build(self) -> (Box<dyn Effect>, Self::Handle) {
+		let (command_producer, command_consumer) = HeapRb::new(COMMAND_CAPACITY).split();
+		let limiter = Limiter::new(self, command_consumer);
+		let shared = limiter.shared();
+		(
+			Box::new(limiter),
+			LimiterHandle {
+				command_producer,
+				shared,
+			},
+		)
+	}
+}
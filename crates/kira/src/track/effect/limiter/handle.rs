@@ -0,0 +1,49 @@
+use std::{sync::Arc, time::Duration};
+
+use ringbuf::HeapProducer;
+
+use crate::{
+	tween::{Tween, Value},
+	CommandError,
+};
+
+use super::{Command, Shared};
+
+/// Controls a limiter.
+pub struct LimiterHandle {
+	pub(super) command_producer: HeapProducer<Command>,
+	pub(super) shared: Arc<Shared>,
+}
+
+impl LimiterHandle {
+	/// Returns how much the limiter delays the audio passing through it.
+	///
+	/// This is the look-ahead duration rounded to the nearest
+	/// audio frame.
+	pub fn latency(&self) -> Duration {
+		self.shared.latency()
+	}
+
+	/// Sets the maximum volume of the output (in dBFS).
+	pub fn set_threshold(
+		&mut self,
+		threshold: impl Into<Value<f64>>,
+		tween: Tween,
+	) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::SetThreshold(threshold.into(), tween))
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+
+	/// Sets how much time it takes for the volume to return to normal
+	/// after a peak has passed.
+	pub fn set_release_duration(
+		&mut self,
+		release_duration: impl Into<Value<Duration>>,
+		tween: Tween,
+	) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::SetReleaseDuration(release_duration.into(), tween))
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use crate::{
+	clock::clock_info::MockClockInfoProviderBuilder,
+	dsp::Frame,
+	modulator::value_provider::MockModulatorValueProviderBuilder,
+	track::effect::{Effect, EffectBuilder},
+};
+
+use super::LimiterBuilder;
+
+/// Tests that a limiter delays the audio by the look-ahead duration
+/// and leaves quiet audio unchanged.
+#[test]
+#[allow(clippy::float_cmp)]
+fn delays_audio() {
+	let (mut limiter, handle) = LimiterBuilder::new()
+		.lookahead_duration(Duration::from_secs(3))
+		.build();
+	limiter.init(1);
+	assert_eq!(handle.latency(), Duration::from_secs(3));
+	let outputs = (1..=6)
+		.map(|i| process(limiter.as_mut(), Frame::from_mono(i as f32 / 10.0)))
+		.collect::<Vec<_>>();
+	assert_eq!(
+		outputs,
+		[0.0, 0.0, 0.0, 0.1, 0.2, 0.3]
+			.iter()
+			.map(|value| Frame::from_mono(*value))
+			.collect::<Vec<_>>()
+	);
+}
+
+/// Tests that a limiter keeps peaks under the threshold, including
+/// the first frame of a peak.
+#[test]
+fn limits_peaks() {
+	let (mut limiter, _) = LimiterBuilder::new()
+		.threshold(-6.0)
+		.lookahead_duration(Duration::from_millis(10))
+		.build();
+	limiter.init(1000);
+	let threshold = 10.0f32.powf(-6.0 / 20.0);
+	let input = (0..100)
+		.map(|i| {
+			if (50..60).contains(&i) {
+				Frame::new(2.0, -1.5)
+			} else {
+				Frame::from_mono(0.1)
+			}
+		})
+		.collect::<Vec<_>>();
+	let mut outputs = vec![];
+	for frame in input.iter().chain(std::iter::repeat(&Frame::ZERO).take(10)) {
+		outputs.push(process(limiter.as_mut(), *frame));
+	}
+	for frame in &outputs {
+		assert!(frame.left.abs() <= threshold + 0.0001);
+		assert!(frame.right.abs() <= threshold + 0.0001);
+	}
+	// the peak should be turned down, not removed
+	assert!(outputs[65].left > threshold * 0.9);
+	// quiet audio well before the peak should be untouched
+	assert!((outputs[20].left - 0.1).abs() < 0.0001);
+}
+
+/// Tests that a limiter holds the lowest gain in the look-ahead window
+/// through peaks of decreasing size, and recovers once they have all
+/// passed.
+#[test]
+fn holds_lowest_gain_in_window() {
+	let (mut limiter, _) = LimiterBuilder::new()
+		.threshold(-6.0)
+		.release_duration(Duration::from_millis(1))
+		.lookahead_duration(Duration::from_millis(10))
+		.build();
+	limiter.init(1000);
+	let threshold = 10.0f32.powf(-6.0 / 20.0);
+	let input = (0..200)
+		.map(|i| {
+			if (50..70).contains(&i) {
+				Frame::from_mono(4.0 - (i - 50) as f32 * 0.15)
+			} else {
+				Frame::from_mono(0.1)
+			}
+		})
+		.collect::<Vec<_>>();
+	let outputs = input
+		.iter()
+		.map(|frame| process(limiter.as_mut(), *frame))
+		.collect::<Vec<_>>();
+	for frame in &outputs {
+		assert!(frame.left.abs() <= threshold + 0.0001);
+	}
+	// once the peaks have left the window, the gain should return to 1
+	assert!((outputs[199].left - 0.1).abs() < 0.0001);
+}
+
+fn process(limiter: &mut dyn Effect, input: Frame) -> Frame {
+	limiter.process(
+		input,
+		1.0 / 1000.0,
+		&MockClockInfoProviderBuilder::new(0).build(),
+		&MockModulatorValueProviderBuilder::new(0).build(),
+	)
+}
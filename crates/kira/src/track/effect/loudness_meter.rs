@@ -0,0 +1,336 @@
+/*!
+Measures the perceived loudness of audio.
+
+Loudness is measured in LUFS (loudness units relative to full scale)
+following the method described in ITU-R BS.1770: the signal is passed
+through K-weighting filters, and the mean square power is measured
+over 400ms blocks.
+
+Two values are reported:
+
+- The **momentary loudness** is the loudness of the most recent 400ms
+  of audio.
+- The **integrated loudness** is the loudness of all of the audio
+  measured since the meter was created (or last reset). Blocks quieter
+  than -70 LUFS, as well as blocks more than 10 LU quieter than the
+  average, are excluded from the measurement, so silence and quiet
+  passages don't drag the value down.
+
+The loudness meter is more expensive to run than most effects, so it
+is only active on tracks it's explicitly added to. To meter the final
+mix, add it to the main track using
+[`AudioManagerSettings::main_track_builder`](crate::manager::AudioManagerSettings::main_track_builder).
+The loudness meter does not change the audio passing through it.
+
+# Examples
+
+```no_run
+use kira::{
+	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	track::{TrackBuilder, effect::loudness_meter::LoudnessMeterBuilder},
+};
+
+let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+let mut loudness_meter;
+let track = manager.add_sub_track({
+	let mut builder = TrackBuilder::new();
+	loudness_meter = builder.add_effect(LoudnessMeterBuilder::new());
+	builder
+})?;
+// later...
+println!("{} LUFS", loudness_meter.integrated_loudness());
+# Result::<(), Box<dyn std::error::Error>>::Ok(())
+```
+*/
+
+mod builder;
+mod handle;
+
+#[cfg(test)]
+mod test;
+
+pub use builder::*;
+pub use handle::*;
+
+use std::{
+	f64::consts::PI,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+};
+
+use ringbuf::HeapConsumer;
+
+use crate::{
+	clock::clock_info::ClockInfoProvider, dsp::Frame,
+	modulator::value_provider::ModulatorValueProvider,
+};
+
+use super::Effect;
+
+/// The number of gating sub-blocks that make up a 400ms measurement block.
+const SUB_BLOCKS_PER_BLOCK: usize = 4;
+/// Blocks quieter than this (in LUFS) are excluded from the
+/// integrated loudness.
+const ABSOLUTE_GATE: f64 = -70.0;
+/// Blocks more than this many LU quieter than the ungated
+/// loudness are excluded from the integrated loudness.
+const RELATIVE_GATE: f64 = -10.0;
+/// The highest loudness (in LUFS) that can be stored in the histogram.
+const HISTOGRAM_MAX: f64 = 10.0;
+/// The number of histogram bins per LU.
+const HISTOGRAM_RESOLUTION: f64 = 10.0;
+const NUM_HISTOGRAM_BINS: usize = ((HISTOGRAM_MAX - ABSOLUTE_GATE) * HISTOGRAM_RESOLUTION) as usize;
+
+enum Command {
+	Reset,
+}
+
+struct LoudnessMeter {
+	command_consumer: HeapConsumer<Command>,
+	shared: Arc<Shared>,
+	filters: [Biquad; 2],
+	filter_states: [[[f64; 2]; 2]; 2],
+	sub_block_length: usize,
+	sub_block_position: usize,
+	sub_block_sum: f64,
+	/// The mean square power of the most recent sub-blocks.
+	sub_blocks: [f64; SUB_BLOCKS_PER_BLOCK],
+	num_sub_blocks: usize,
+	/// The number of blocks and the sum of their mean square
+	/// power, grouped by loudness.
+	histogram: Box<[(u64, f64)]>,
+}
+
+impl LoudnessMeter {
+	fn new(command_consumer: HeapConsumer<Command>) -> Self {
+		Self {
+			command_consumer,
+			shared: Arc::new(Shared::new()),
+			filters: [Biquad::IDENTITY; 2],
+			filter_states: [[[0.0; 2]; 2]; 2],
+			sub_block_length: 1,
+			sub_block_position: 0,
+			sub_block_sum: 0.0,
+			sub_blocks: [0.0; SUB_BLOCKS_PER_BLOCK],
+			num_sub_blocks: 0,
+			histogram: vec![(0, 0.0); NUM_HISTOGRAM_BINS].into_boxed_slice(),
+		}
+	}
+
+	fn shared(&self) -> Arc<Shared> {
+		self.shared.clone()
+	}
+
+	fn set_sample_rate(&mut self, sample_rate: u32) {
+		self.filters = [
+			Biquad::k_weighting_shelf(sample_rate),
+			Biquad::k_weighting_high_pass(sample_rate),
+		];
+		// measurement blocks are 400ms long and overlap by 75%, so a new
+		// block is completed every 100ms
+		self.sub_block_length = (sample_rate as usize / 10).max(1);
+		self.reset();
+	}
+
+	fn reset(&mut self) {
+		self.filter_states = [[[0.0; 2]; 2]; 2];
+		self.sub_block_position = 0;
+		self.sub_block_sum = 0.0;
+		self.sub_blocks = [0.0; SUB_BLOCKS_PER_BLOCK];
+		self.num_sub_blocks = 0;
+		for bin in self.histogram.iter_mut() {
+			*bin = (0, 0.0);
+		}
+		self.shared.set_momentary_loudness(f64::NEG_INFINITY);
+		self.shared.set_integrated_loudness(f64::NEG_INFINITY);
+	}
+
+	fn finish_sub_block(&mut self) {
+		self.sub_blocks.rotate_left(1);
+		self.sub_blocks[SUB_BLOCKS_PER_BLOCK - 1] =
+			self.sub_block_sum / self.sub_block_length as f64;
+		self.sub_block_sum = 0.0;
+		self.sub_block_position = 0;
+		self.num_sub_blocks = (self.num_sub_blocks + 1).min(SUB_BLOCKS_PER_BLOCK);
+		if self.num_sub_blocks < SUB_BLOCKS_PER_BLOCK {
+			return;
+		}
+		let block_power = self.sub_blocks.iter().sum::<f64>() / SUB_BLOCKS_PER_BLOCK as f64;
+		let block_loudness = power_to_loudness(block_power);
+		self.shared.set_momentary_loudness(block_loudness);
+		if block_loudness > ABSOLUTE_GATE {
+			let bin = &mut self.histogram[histogram_bin(block_loudness)];
+			bin.0 += 1;
+			bin.1 += block_power;
+			self.shared
+				.set_integrated_loudness(self.calculate_integrated_loudness());
+		}
+	}
+
+	fn calculate_integrated_loudness(&self) -> f64 {
+		let (num_blocks, total_power) = self
+			.histogram
+			.iter()
+			.fold((0, 0.0), |(count, sum), bin| (count + bin.0, sum + bin.1));
+		if num_blocks == 0 {
+			return f64::NEG_INFINITY;
+		}
+		let relative_gate = power_to_loudness(total_power / num_blocks as f64) + RELATIVE_GATE;
+		let first_bin = if relative_gate > ABSOLUTE_GATE {
+			histogram_bin(relative_gate)
+		} else {
+			0
+		};
+		let (num_gated_blocks, gated_power) = self.histogram[first_bin..]
+			.iter()
+			.fold((0, 0.0), |(count, sum), bin| (count + bin.0, sum + bin.1));
+		if num_gated_blocks == 0 {
+			return f64::NEG_INFINITY;
+		}
+		power_to_loudness(gated_power / num_gated_blocks as f64)
+	}
+}
+
+impl Effect for LoudnessMeter {
+	fn init(&mut self, sample_rate: u32) {
+		self.set_sample_rate(sample_rate);
+	}
+
+	fn on_change_sample_rate(&mut self, sample_rate: u32) {
+		self.set_sample_rate(sample_rate);
+	}
+
+	fn on_start_processing(&mut self) {
+		while let Some(command) = self.command_consumer.pop() {
+			match command {
+				Command::Reset => self.reset(),
+			}
+		}
+	}
+
+	fn process(
+		&mut self,
+		input: Frame,
+		_dt: f64,
+		_clock_info_provider: &ClockInfoProvider,
+		_modulator_value_provider: &ModulatorValueProvider,
+	) -> Frame {
+		for (channel, sample) in [input.left, input.right].iter().enumerate() {
+			let mut sample = *sample as f64;
+			for (filter, states) in self.filters.iter().zip(self.filter_states.iter_mut()) {
+				sample = filter.process(sample, &mut states[channel]);
+			}
+			self.sub_block_sum += sample * sample;
+		}
+		self.sub_block_position += 1;
+		if self.sub_block_position >= self.sub_block_length {
+			self.finish_sub_block();
+		}
+		input
+	}
+}
+
+struct Shared {
+	momentary_loudness: AtomicU64,
+	integrated_loudness: AtomicU64,
+}
+
+impl Shared {
+	fn new() -> Self {
+		Self {
+			momentary_loudness: AtomicU64::new(f64::NEG_INFINITY.to_bits()),
+			integrated_loudness: AtomicU64::new(f64::NEG_INFINITY.to_bits()),
+		}
+	}
+
+	fn momentary_loudness(&self) -> f64 {
+		f64::from_bits(self.momentary_loudness.load(Ordering::SeqCst))
+	}
+
+	fn set_momentary_loudness(&self, loudness: f64) {
+		self.momentary_loudness
+			.store(loudness.to_bits(), Ordering::SeqCst);
+	}
+
+	fn integrated_loudness(&self) -> f64 {
+		f64::from_bits(self.integrated_loudness.load(Ordering::SeqCst))
+	}
+
+	fn set_integrated_loudness(&self, loudness: f64) {
+		self.integrated_loudness
+			.store(loudness.to_bits(), Ordering::SeqCst);
+	}
+}
+
+/// A second order IIR filter.
+struct Biquad {
+	b0: f64,
+	b1: f64,
+	b2: f64,
+	a1: f64,
+	a2: f64,
+}
+
+impl Biquad {
+	const IDENTITY: Self = Self {
+		b0: 1.0,
+		b1: 0.0,
+		b2: 0.0,
+		a1: 0.0,
+		a2: 0.0,
+	};
+
+	/// The first stage of the K-weighting filter, which models the
+	/// acoustic effects of the head.
+	// the constants for the K-weighting filters are taken from libebur128,
+	// which derives them from the 48kHz coefficients in ITU-R BS.1770
+	fn k_weighting_shelf(sample_rate: u32) -> Self {
+		const FREQUENCY: f64 = 1681.974450955533;
+		const GAIN: f64 = 3.999843853973347;
+		const Q: f64 = 0.7071752369554196;
+		let k = (PI * FREQUENCY / sample_rate as f64).tan();
+		let vh = 10.0f64.powf(GAIN / 20.0);
+		let vb = vh.powf(0.4996667741545416);
+		let a0 = 1.0 + k / Q + k * k;
+		Self {
+			b0: (vh + vb * k / Q + k * k) / a0,
+			b1: 2.0 * (k * k - vh) / a0,
+			b2: (vh - vb * k / Q + k * k) / a0,
+			a1: 2.0 * (k * k - 1.0) / a0,
+			a2: (1.0 - k / Q + k * k) / a0,
+		}
+	}
+
+	/// The second stage of the K-weighting filter, which removes
+	/// low frequencies.
+	fn k_weighting_high_pass(sample_rate: u32) -> Self {
+		const FREQUENCY: f64 = 38.13547087602444;
+		const Q: f64 = 0.5003270373238773;
+		let k = (PI * FREQUENCY / sample_rate as f64).tan();
+		let a0 = 1.0 + k / Q + k * k;
+		Self {
+			b0: 1.0,
+			b1: -2.0,
+			b2: 1.0,
+			a1: 2.0 * (k * k - 1.0) / a0,
+			a2: (1.0 - k / Q + k * k) / a0,
+		}
+	}
+
+	fn process(&self, input: f64, state: &mut [f64; 2]) -> f64 {
+		let output = self.b0 * input + state[0];
+		state[0] = self.b1 * input - self.a1 * output + state[1];
+		state[1] = self.b2 * input - self.a2 * output;
+		output
+	}
+}
+
+fn power_to_loudness(power: f64) -> f64 {
+	-0.691 + 10.0 * power.log10()
+}
+
+fn histogram_bin(loudness: f64) -> usize {
+	(((loudness - ABSOLUTE_GATE) * HISTOGRAM_RESOLUTION) as usize).min(NUM_HISTOGRAM_BINS - 1)
+}
@@ -0,0 +1,35 @@
+use ringbuf::HeapRb;
+
+use crate::track::effect::{Effect, EffectBuilder};
+
+use super::{LoudnessMeter, LoudnessMeterHandle};
+
+const COMMAND_CAPACITY: usize = 8;
+
+/// Configures a loudness meter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LoudnessMeterBuilder;
+
+impl LoudnessMeterBuilder {
+	/// Creates a new [`LoudnessMeterBuilder`].
+	pub fn new() -> Self {
+		Self
+	}
+}
+
+impl EffectBuilder for LoudnessMeterBuilder {
+	type Handle = LoudnessMeterHandle;
+
+	fn build(self) -> (Box<dyn Effect>, Self::Handle) {
+		let (command_producer, command_consumer) = HeapRb::new(COMMAND_CAPACITY).split();
+		let loudness_meter = LoudnessMeter::new(command_consumer);
+		let shared = loudness_meter.shared();
+		(
+			Box::new(loudness_meter),
+			LoudnessMeterHandle {
+				command_producer,
+				shared,
+			},
+		)
+	}
+}
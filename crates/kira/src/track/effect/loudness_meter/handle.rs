@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use ringbuf::HeapProducer;
+
+use crate::CommandError;
+
+use super::{Command, Shared};
+
+/// Controls a loudness meter.
+pub struct LoudnessMeterHandle {
+	pub(super) command_producer: HeapProducer<Command>,
+	pub(super) shared: Arc<Shared>,
+}
+
+impl LoudnessMeterHandle {
+	/// Returns the loudness of the most recent 400ms of audio (in LUFS).
+	///
+	/// Returns [`f64::NEG_INFINITY`] if the meter hasn't received 400ms
+	/// of audio yet or the audio is completely silent.
+	pub fn momentary_loudness(&self) -> f64 {
+		self.shared.momentary_loudness()
+	}
+
+	/// Returns the loudness of all of the audio measured so far (in LUFS).
+	///
+	/// Returns [`f64::NEG_INFINITY`] if no audio louder than -70 LUFS
+	/// has been measured yet.
+	pub fn integrated_loudness(&self) -> f64 {
+		self.shared.integrated_loudness()
+	}
+
+	/// Discards all of the audio measured so far.
+	pub fn reset(&mut self) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Reset)
+			.map_err(|_| CommandError::CommandQueueFull)
+	}
+}
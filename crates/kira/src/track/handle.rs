@@ -2,7 +2,7 @@ use std::{collections::HashSet, error::Error, fmt::Display, sync::Arc};
 
 use crate::{
 	error::CommandError,
-	manager::command::{producer::CommandProducer, Command, MixerCommand},
+	manager::command::{producer::CommandProducer, Command, MixerCommand, SoundCommand},
 	tween::{Tween, Value},
 	Volume,
 };
@@ -92,6 +92,29 @@ impl TrackHandle {
 			}))
 			.map_err(SetRouteError::CommandError)
 	}
+
+	/// Fades out and pauses every sound that is playing on this track.
+	///
+	/// Only sounds that output directly to this track are affected.
+	/// Sounds that are already paused will stay paused when
+	/// [`resume_all`](TrackHandle::resume_all) is called.
+	pub fn pause_all(&self, fade_out_tween: Tween) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Sound(SoundCommand::PauseAll(
+				Some(self.id),
+				fade_out_tween,
+			)))
+	}
+
+	/// Resumes and fades in every sound on this track that was paused by
+	/// [`pause_all`](TrackHandle::pause_all).
+	pub fn resume_all(&self, fade_in_tween: Tween) -> Result<(), CommandError> {
+		self.command_producer
+			.push(Command::Sound(SoundCommand::ResumeAll(
+				Some(self.id),
+				fade_in_tween,
+			)))
+	}
 }
 
 impl Drop for TrackHandle {
@@ -1,8 +1,10 @@
 //! Smooth interpolation between values.
 
+mod automation;
 mod parameter;
 mod tweenable;
 
+pub use automation::*;
 pub use parameter::*;
 pub use tweenable::*;
 
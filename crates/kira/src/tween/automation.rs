@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use crate::StartTime;
+
+use super::{Easing, Tweenable};
+
+const MAX_KEYFRAMES: usize = 16;
+
+/// One stage of an [`Automation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe<T: Tweenable> {
+	/// The value to move to.
+	pub value: T,
+	/// How long it takes to reach the value from the
+	/// previous keyframe.
+	pub duration: Duration,
+	/// The curve of the motion.
+	pub easing: Easing,
+}
+
+/**
+Moves a value through a series of [`Keyframe`]s one after another.
+
+An automation lets a value follow several stages (for example, fade up,
+hold, then fade down) with a single command instead of scheduling each
+[`Tween`](super::Tween) separately. An automation with one keyframe
+behaves the same as a [`Tween`](super::Tween) to that keyframe's value.
+
+Automations have a fixed capacity of [`Automation::MAX_KEYFRAMES`]
+keyframes so they can be sent to the audio thread without allocating.
+
+# Examples
+
+```
+use std::time::Duration;
+use kira::tween::{Automation, Easing};
+
+// fade up, hold for a second, then fade down
+let automation = Automation::new()
+	.keyframe(1.0, Duration::from_millis(500), Easing::Linear)
+	.keyframe(1.0, Duration::from_secs(1), Easing::Linear)
+	.keyframe(0.0, Duration::from_millis(500), Easing::OutPowi(2));
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Automation<T: Tweenable> {
+	/// When the automation starts.
+	pub(crate) start_time: StartTime,
+	/// The keyframes in order, followed by `None` for each
+	/// unused slot.
+	keyframes: [Option<Keyframe<T>>; MAX_KEYFRAMES],
+}
+
+impl<T: Tweenable> Automation<T> {
+	/// The maximum number of keyframes an automation can have.
+	pub const MAX_KEYFRAMES: usize = MAX_KEYFRAMES;
+
+	/// Creates a new [`Automation`] with no keyframes.
+	pub fn new() -> Self {
+		Self {
+			start_time: StartTime::default(),
+			keyframes: [None; MAX_KEYFRAMES],
+		}
+	}
+
+	/// Sets when the automation starts.
+	pub fn start_time(self, start_time: impl Into<StartTime>) -> Self {
+		Self {
+			start_time: start_time.into(),
+			..self
+		}
+	}
+
+	/**
+	Adds a keyframe to the end of the automation.
+
+	# Panics
+
+	Panics if the automation already has [`Automation::MAX_KEYFRAMES`]
+	keyframes.
+	*/
+	pub fn keyframe(mut self, value: T, duration: Duration, easing: Easing) -> Self {
+		let slot = self
+			.keyframes
+			.iter_mut()
+			.find(|slot| slot.is_none())
+			.unwrap_or_else(|| {
+				panic!(
+					"an automation cannot have more than {} keyframes",
+					Self::MAX_KEYFRAMES
+				)
+			});
+		*slot = Some(Keyframe {
+			value,
+			duration,
+			easing,
+		});
+		self
+	}
+
+	/// Returns the keyframes of the automation.
+	pub fn keyframes(&self) -> impl Iterator<Item = Keyframe<T>> + '_ {
+		self.keyframes.iter().map_while(|keyframe| *keyframe)
+	}
+
+	/// Returns the number of keyframes in the automation.
+	pub fn len(&self) -> usize {
+		self.keyframes().count()
+	}
+
+	/// Returns `true` if the automation has no keyframes.
+	pub fn is_empty(&self) -> bool {
+		self.keyframes[0].is_none()
+	}
+
+	pub(crate) fn get(&self, index: usize) -> Option<Keyframe<T>> {
+		self.keyframes.get(index).copied().flatten()
+	}
+}
+
+impl<T: Tweenable> Default for Automation<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -5,12 +5,18 @@ mod value;
 
 pub use value::*;
 
+use std::time::Duration;
+
 use crate::{
 	clock::clock_info::{ClockInfoProvider, WhenToStart},
 	modulator::value_provider::ModulatorValueProvider,
-	tween::{Tween, Tweenable},
+	tween::{Automation, Tween, Tweenable},
 };
 
+/// How many time constants smoothing runs for before the value is
+/// considered to have reached its target.
+const SETTLING_TIME_CONSTANTS: f64 = 10.0;
+
 /// Manages and updates a value that can be smoothly transitioned
 /// and linked to modulators.
 ///
@@ -25,6 +31,11 @@ pub struct Parameter<T: Tweenable = f64> {
 	state: State<T>,
 	raw_value: T,
 	stagnant: bool,
+	/// The time constant (in seconds) of the smoothing filter, if
+	/// smoothing is enabled.
+	smoothing: Option<f64>,
+	/// How long the parameter has been settling toward a fixed value.
+	settling_time: f64,
 }
 
 impl<T: Tweenable> Parameter<T> {
@@ -42,6 +53,27 @@ impl<T: Tweenable> Parameter<T> {
 				Value::FromModulator { .. } => default_raw_value,
 			},
 			stagnant: matches!(initial_value, Value::Fixed(_)),
+			smoothing: None,
+			settling_time: 0.0,
+		}
+	}
+
+	/**
+	Enables smoothing with the given time constant.
+
+	When smoothing is enabled, the value glides toward its target
+	instead of jumping to it, which avoids "zipper noise" when a
+	parameter is changed many times in quick succession without
+	tweens. The time constant is how long it takes the value to move
+	about 63% of the way to the target.
+
+	Smoothing is applied on top of any tweens, automations, and
+	modulators, so it also adds a slight lag to them.
+	*/
+	pub fn with_smoothing(self, time_constant: Duration) -> Self {
+		Self {
+			smoothing: Some(time_constant.as_secs_f64()),
+			..self
 		}
 	}
 
@@ -53,6 +85,7 @@ impl<T: Tweenable> Parameter<T> {
 	/// Starts a transition from the current value to the target value.
 	pub fn set(&mut self, target: Value<T>, tween: Tween) {
 		self.stagnant = false;
+		self.settling_time = 0.0;
 		self.state = State::Tweening {
 			start: self.value(),
 			target,
@@ -61,10 +94,62 @@ impl<T: Tweenable> Parameter<T> {
 		};
 	}
 
+	/// Starts moving the value through the keyframes of an [`Automation`],
+	/// starting from the current value.
+	///
+	/// Setting or automating the parameter again interrupts the
+	/// automation, and the new transition starts from wherever the
+	/// automation left off.
+	pub fn automate(&mut self, automation: Automation<T>) {
+		self.stagnant = false;
+		self.settling_time = 0.0;
+		self.state = State::Automating {
+			automation,
+			keyframe_index: 0,
+			keyframe_start: self.value(),
+			time: 0.0,
+		};
+	}
+
+	/// Stops any in-progress transition, automation, or smoothing and
+	/// holds the current value.
+	///
+	/// If the parameter is linked to a modulator, it keeps following
+	/// the modulator.
+	pub fn cancel_tween(&mut self) {
+		if matches!(
+			self.state,
+			State::Idle {
+				value: Value::FromModulator { .. }
+			}
+		) {
+			return;
+		}
+		self.state = State::Idle {
+			value: Value::Fixed(self.raw_value),
+		};
+		self.stagnant = true;
+	}
+
+	/// Stops any in-progress transition or automation and immediately
+	/// sets the parameter to the given value.
+	///
+	/// Unlike [`set`](Parameter::set) with a zero-duration tween, this
+	/// takes effect right away (even before the next update) and skips
+	/// any smoothing.
+	pub fn set_immediate(&mut self, value: T) {
+		self.state = State::Idle {
+			value: Value::Fixed(value),
+		};
+		self.raw_value = value;
+		self.stagnant = true;
+	}
+
 	/// Updates any in-progress transitions and keeps the value up-to-date
 	/// with any linked modulators.
 	///
-	/// Returns `true` if a transition just finished after this update.
+	/// Returns `true` if a transition or automation just finished after
+	/// this update.
 	pub fn update(
 		&mut self,
 		dt: f64,
@@ -76,16 +161,44 @@ impl<T: Tweenable> Parameter<T> {
 		}
 		let just_finished_tween = self.update_tween(dt, clock_info_provider);
 		if let Some(raw_value) = self.calculate_new_raw_value(modulator_value_provider) {
-			self.raw_value = raw_value;
+			self.raw_value = match self.smoothing {
+				Some(time_constant) if time_constant > 0.0 => {
+					T::interpolate(self.raw_value, raw_value, 1.0 - (-dt / time_constant).exp())
+				}
+				_ => raw_value,
+			};
 		}
+		self.update_stagnant(dt);
 		just_finished_tween
 	}
 
+	/// Stops updating the parameter once it's reached a fixed value.
+	fn update_stagnant(&mut self, dt: f64) {
+		if let State::Idle {
+			value: Value::Fixed(value),
+		} = self.state
+		{
+			match self.smoothing {
+				Some(time_constant) => {
+					self.settling_time += dt;
+					if self.settling_time >= time_constant * SETTLING_TIME_CONSTANTS {
+						self.raw_value = value;
+						self.stagnant = true;
+					}
+				}
+				None => self.stagnant = true,
+			}
+		}
+	}
+
 	fn update_tween(
 		&mut self,
 		dt: f64,
 		clock_info_provider: &ClockInfoProvider,
 	) -> JustFinishedTween {
+		if let State::Automating { .. } = &self.state {
+			return self.update_automation(dt, clock_info_provider);
+		}
 		if let State::Tweening {
 			target,
 			time,
@@ -98,9 +211,6 @@ impl<T: Tweenable> Parameter<T> {
 			}
 			*time += dt;
 			if *time >= tween.duration.as_secs_f64() {
-				if matches!(target, Value::Fixed(_)) {
-					self.stagnant = true;
-				}
 				self.state = State::Idle { value: *target };
 				return true;
 			}
@@ -108,6 +218,42 @@ impl<T: Tweenable> Parameter<T> {
 		false
 	}
 
+	fn update_automation(
+		&mut self,
+		dt: f64,
+		clock_info_provider: &ClockInfoProvider,
+	) -> JustFinishedTween {
+		if let State::Automating {
+			automation,
+			keyframe_index,
+			keyframe_start,
+			time,
+		} = &mut self.state
+		{
+			if clock_info_provider.when_to_start(automation.start_time) != WhenToStart::Now {
+				return false;
+			}
+			*time += dt;
+			// move on to the next keyframe once the current one is finished,
+			// carrying over any leftover time
+			while let Some(keyframe) = automation.get(*keyframe_index) {
+				let duration = keyframe.duration.as_secs_f64();
+				if *time < duration {
+					return false;
+				}
+				*time -= duration;
+				*keyframe_start = keyframe.value;
+				*keyframe_index += 1;
+			}
+			let end_value = *keyframe_start;
+			self.state = State::Idle {
+				value: Value::Fixed(end_value),
+			};
+			return true;
+		}
+		false
+	}
+
 	fn calculate_new_raw_value(
 		&self,
 		modulator_value_provider: &ModulatorValueProvider,
@@ -127,6 +273,18 @@ impl<T: Tweenable> Parameter<T> {
 					.raw_value(modulator_value_provider)
 					.map(|target| T::interpolate(*start, target, tween.value(*time)))
 			}
+			State::Automating {
+				automation,
+				keyframe_index,
+				keyframe_start,
+				time,
+			} => {
+				let keyframe = automation.get(*keyframe_index)?;
+				let amount = keyframe
+					.easing
+					.apply(*time / keyframe.duration.as_secs_f64());
+				Some(T::interpolate(*keyframe_start, keyframe.value, amount))
+			}
 		}
 	}
 }
@@ -142,6 +300,13 @@ enum State<T: Tweenable> {
 		time: f64,
 		tween: Tween,
 	},
+	Automating {
+		automation: Automation<T>,
+		keyframe_index: usize,
+		/// The value at the end of the previous keyframe.
+		keyframe_start: T,
+		time: f64,
+	},
 }
 
 type JustFinishedTween = bool;
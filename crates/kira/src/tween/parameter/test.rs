@@ -6,7 +6,7 @@ use crate::{
 		ClockTime,
 	},
 	modulator::value_provider::MockModulatorValueProviderBuilder,
-	tween::{Tween, Value},
+	tween::{Automation, Easing, Tween, Value},
 	StartTime,
 };
 
@@ -202,3 +202,193 @@ fn tweens_to_modulator_values() {
 		assert_eq!(parameter.value(), time * time);
 	}
 }
+
+/// Tests that a `Parameter` moves through each keyframe of an
+/// automation in order.
+#[test]
+#[allow(clippy::float_cmp)]
+fn automation() {
+	let mut parameter = Parameter::new(Value::Fixed(0.0), 0.0);
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	parameter.automate(
+		Automation::new()
+			.keyframe(1.0, Duration::from_secs(2), Easing::Linear)
+			.keyframe(1.0, Duration::from_secs(1), Easing::Linear)
+			.keyframe(0.0, Duration::from_secs(2), Easing::Linear),
+	);
+
+	for expected_value in [0.5, 1.0, 1.0, 0.5] {
+		assert!(!parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+		assert_eq!(parameter.value(), expected_value);
+	}
+	assert!(parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+	assert_eq!(parameter.value(), 0.0);
+	assert!(!parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+	assert_eq!(parameter.value(), 0.0);
+}
+
+/// Tests that an automation with a single keyframe behaves
+/// the same as a tween.
+#[test]
+#[allow(clippy::float_cmp)]
+fn single_keyframe_automation_matches_tween() {
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+	let easing = Easing::InOutPowi(3);
+
+	let mut tweened = Parameter::new(Value::Fixed(0.25), 0.0);
+	tweened.set(
+		Value::Fixed(2.0),
+		Tween {
+			duration: Duration::from_secs(1),
+			easing,
+			..Default::default()
+		},
+	);
+	let mut automated = Parameter::new(Value::Fixed(0.25), 0.0);
+	automated.automate(Automation::new().keyframe(2.0, Duration::from_secs(1), easing));
+
+	for _ in 0..15 {
+		assert_eq!(
+			tweened.update(0.1, &clock_info_provider, &modulator_value_provider),
+			automated.update(0.1, &clock_info_provider, &modulator_value_provider)
+		);
+		assert_eq!(tweened.value(), automated.value());
+	}
+}
+
+/// Tests that a tween started during an automation starts
+/// from the automation's current value.
+#[test]
+#[allow(clippy::float_cmp)]
+fn interrupting_automation() {
+	let mut parameter = Parameter::new(Value::Fixed(0.0), 0.0);
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	parameter.automate(
+		Automation::new()
+			.keyframe(1.0, Duration::from_secs(2), Easing::Linear)
+			.keyframe(0.0, Duration::from_secs(2), Easing::Linear),
+	);
+	parameter.update(1.0, &clock_info_provider, &modulator_value_provider);
+	assert_eq!(parameter.value(), 0.5);
+
+	parameter.set(
+		Value::Fixed(2.5),
+		Tween {
+			duration: Duration::from_secs(2),
+			..Default::default()
+		},
+	);
+	assert!(!parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+	assert_eq!(parameter.value(), 1.5);
+	assert!(parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+	assert_eq!(parameter.value(), 2.5);
+}
+
+/// Tests that a `Parameter` with smoothing enabled glides toward
+/// new values and eventually settles on them exactly.
+#[test]
+#[allow(clippy::float_cmp)]
+fn smoothing() {
+	let mut parameter =
+		Parameter::new(Value::Fixed(0.0), 0.0).with_smoothing(Duration::from_secs(1));
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	parameter.set(
+		Value::Fixed(1.0),
+		Tween {
+			duration: Duration::ZERO,
+			..Default::default()
+		},
+	);
+	assert!(parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+	assert!((parameter.value() - (1.0 - (-1.0f64).exp())).abs() < 1.0e-9);
+	assert!(!parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+	assert!((parameter.value() - (1.0 - (-2.0f64).exp())).abs() < 1.0e-9);
+
+	// after enough time has passed, the parameter should land on the
+	// target value
+	for _ in 0..8 {
+		parameter.update(1.0, &clock_info_provider, &modulator_value_provider);
+	}
+	assert_eq!(parameter.value(), 1.0);
+}
+
+/// Tests that cancelling a tween stops the value from moving.
+#[test]
+#[allow(clippy::float_cmp)]
+fn cancel_tween() {
+	let mut parameter = Parameter::new(Value::Fixed(0.0), 0.0);
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	parameter.set(
+		Value::Fixed(1.0),
+		Tween {
+			duration: Duration::from_secs(2),
+			..Default::default()
+		},
+	);
+	parameter.update(1.0, &clock_info_provider, &modulator_value_provider);
+	assert_eq!(parameter.value(), 0.5);
+
+	parameter.cancel_tween();
+	for _ in 0..3 {
+		assert!(!parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+		assert_eq!(parameter.value(), 0.5);
+	}
+}
+
+/// Tests that cancelling a tween on a smoothed parameter that has
+/// already reached its target stops the value from gliding.
+#[test]
+#[allow(clippy::float_cmp)]
+fn cancel_tween_while_smoothing() {
+	let mut parameter =
+		Parameter::new(Value::Fixed(0.0), 0.0).with_smoothing(Duration::from_secs(1));
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	parameter.set(Value::Fixed(1.0), Tween::default());
+	parameter.update(1.0, &clock_info_provider, &modulator_value_provider);
+	let held_value = parameter.value();
+	assert!(held_value > 0.0 && held_value < 1.0);
+
+	parameter.cancel_tween();
+	for _ in 0..3 {
+		parameter.update(1.0, &clock_info_provider, &modulator_value_provider);
+		assert_eq!(parameter.value(), held_value);
+	}
+}
+
+/// Tests that setting a value immediately cancels any tween
+/// and changes the value right away.
+#[test]
+#[allow(clippy::float_cmp)]
+fn set_immediate() {
+	let mut parameter =
+		Parameter::new(Value::Fixed(0.0), 0.0).with_smoothing(Duration::from_secs(1));
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+
+	parameter.set(
+		Value::Fixed(1.0),
+		Tween {
+			duration: Duration::from_secs(2),
+			..Default::default()
+		},
+	);
+	parameter.update(1.0, &clock_info_provider, &modulator_value_provider);
+
+	parameter.set_immediate(3.0);
+	assert_eq!(parameter.value(), 3.0);
+	for _ in 0..3 {
+		assert!(!parameter.update(1.0, &clock_info_provider, &modulator_value_provider));
+		assert_eq!(parameter.value(), 3.0);
+	}
+}
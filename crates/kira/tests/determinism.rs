@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use kira::{
+	clock::ClockSpeed,
+	dsp::Frame,
+	manager::{
+		backend::mock::{MockBackend, MockBackendSettings},
+		AudioManager, AudioManagerSettings,
+	},
+	modulator::lfo::{LfoBuilder, Waveform},
+	sound::static_sound::{Signal, SignalGenerator, StaticSoundData, StaticSoundSettings},
+	track::{
+		effect::{filter::FilterBuilder, reverb::ReverbBuilder},
+		TrackBuilder,
+	},
+	tween::Tween,
+};
+
+const SAMPLE_RATE: u32 = 1_000;
+const BLOCK_SIZE: usize = 100;
+const NUM_BLOCKS: usize = 30;
+
+/// Plays the same sequence of commands and renders the output.
+fn render_timeline() -> Vec<Frame> {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+		backend_settings: MockBackendSettings {
+			sample_rate: SAMPLE_RATE,
+		},
+		..Default::default()
+	})
+	.unwrap();
+	let lfo = manager
+		.add_modulator(
+			LfoBuilder::new()
+				.frequency(3.0)
+				.amplitude(200.0)
+				.offset(300.0),
+		)
+		.unwrap();
+	let track = manager
+		.add_sub_track({
+			let mut builder = TrackBuilder::new();
+			builder.add_effect(FilterBuilder::new().cutoff(&lfo));
+			builder.add_effect(ReverbBuilder::new().mix(0.5));
+			builder
+		})
+		.unwrap();
+	let clock = manager.add_clock(ClockSpeed::TicksPerSecond(10.0)).unwrap();
+	let noise = StaticSoundData::generate(
+		SignalGenerator::new(Signal::WhiteNoise, Duration::from_secs(1)).sample_rate(SAMPLE_RATE),
+		StaticSoundSettings::new()
+			.output_destination(&track)
+			.loop_region(..),
+	);
+	let tone = StaticSoundData::generate(
+		SignalGenerator::new(
+			Signal::Tone {
+				waveform: Waveform::Sine,
+				frequency: 110.0,
+			},
+			Duration::from_millis(500),
+		)
+		.sample_rate(SAMPLE_RATE),
+		StaticSoundSettings::new().start_time(clock.time() + 5),
+	);
+
+	let mut noise_handle = manager.play(noise).unwrap();
+	manager.play(tone.clone()).unwrap();
+	clock.start().unwrap();
+
+	let mut output = vec![];
+	for block in 0..NUM_BLOCKS {
+		match block {
+			5 => noise_handle
+				.set_playback_rate(
+					0.5,
+					Tween {
+						duration: Duration::from_millis(250),
+						..Default::default()
+					},
+				)
+				.unwrap(),
+			10 => noise_handle
+				.pause(Tween {
+					duration: Duration::from_millis(150),
+					..Default::default()
+				})
+				.unwrap(),
+			15 => {
+				manager.play(tone.clone()).unwrap();
+			}
+			20 => noise_handle.resume(Tween::default()).unwrap(),
+			_ => {}
+		}
+		let backend = manager.backend_mut();
+		backend.on_start_processing();
+		for _ in 0..BLOCK_SIZE {
+			output.push(backend.process());
+		}
+	}
+	output
+}
+
+/// Tests that rendering the same timeline twice produces
+/// identical output.
+#[test]
+fn renders_identical_output() {
+	let first = render_timeline();
+	let second = render_timeline();
+	assert!(first.iter().any(|frame| *frame != Frame::ZERO));
+	assert_eq!(first.len(), second.len());
+	for (i, (a, b)) in first.iter().zip(second.iter()).enumerate() {
+		assert!(
+			a.left.to_bits() == b.left.to_bits() && a.right.to_bits() == b.right.to_bits(),
+			"frame {} differs: {:?} != {:?}",
+			i,
+			a,
+			b
+		);
+	}
+}
+
+/// Tests that `MockBackend::render` produces the same output
+/// as processing the audio manually.
+#[test]
+fn render_matches_manual_processing() {
+	let create_manager = || {
+		let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+			backend_settings: MockBackendSettings {
+				sample_rate: SAMPLE_RATE,
+			},
+			..Default::default()
+		})
+		.unwrap();
+		manager
+			.play(StaticSoundData::generate(
+				SignalGenerator::new(Signal::WhiteNoise, Duration::from_millis(250))
+					.sample_rate(SAMPLE_RATE),
+				StaticSoundSettings::new().loop_region(..),
+			))
+			.unwrap();
+		manager
+	};
+
+	let mut manager = create_manager();
+	let rendered = manager
+		.backend_mut()
+		.render(Duration::from_millis(1050), BLOCK_SIZE);
+
+	let mut manager = create_manager();
+	let backend = manager.backend_mut();
+	let mut processed = vec![];
+	for _ in 0..11 {
+		backend.on_start_processing();
+		for _ in 0..BLOCK_SIZE {
+			processed.push(backend.process());
+		}
+	}
+	processed.truncate(1050);
+
+	assert_eq!(rendered, processed);
+}
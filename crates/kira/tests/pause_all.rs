@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use kira::{
+	dsp::Frame,
+	manager::{backend::mock::MockBackend, AudioManager, AudioManagerSettings},
+	sound::{
+		static_sound::{StaticSoundData, StaticSoundSettings},
+		PlaybackState,
+	},
+	track::TrackBuilder,
+	tween::Tween,
+};
+
+fn sound_data() -> StaticSoundData {
+	StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(1.0); 100]),
+		settings: StaticSoundSettings::new(),
+	}
+}
+
+fn render(manager: &mut AudioManager<MockBackend>) {
+	let backend = manager.backend_mut();
+	backend.on_start_processing();
+	backend.process();
+}
+
+/// Tests that `AudioManager::pause_all` pauses every sound and
+/// `AudioManager::resume_all` only resumes the sounds it paused.
+#[test]
+fn pause_all_and_resume_all() {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings::default()).unwrap();
+	let sound_1 = manager.play(sound_data()).unwrap();
+	let mut sound_2 = manager.play(sound_data()).unwrap();
+	render(&mut manager);
+
+	sound_2.pause(Tween::default()).unwrap();
+	manager.pause_all(Tween::default()).unwrap();
+	render(&mut manager);
+	assert_eq!(sound_1.state(), PlaybackState::Paused);
+	assert_eq!(sound_2.state(), PlaybackState::Paused);
+
+	manager.resume_all(Tween::default()).unwrap();
+	render(&mut manager);
+	assert_eq!(sound_1.state(), PlaybackState::Playing);
+	assert_eq!(sound_2.state(), PlaybackState::Paused);
+}
+
+/// Tests that `TrackHandle::pause_all` only pauses sounds on that track.
+#[test]
+fn pause_all_on_track() {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings::default()).unwrap();
+	let track = manager.add_sub_track(TrackBuilder::new()).unwrap();
+	let main_track_sound = manager.play(sound_data()).unwrap();
+	let sub_track_sound = manager
+		.play(sound_data().with_modified_settings(|settings| settings.output_destination(&track)))
+		.unwrap();
+	render(&mut manager);
+
+	track.pause_all(Tween::default()).unwrap();
+	render(&mut manager);
+	assert_eq!(main_track_sound.state(), PlaybackState::Playing);
+	assert_eq!(sub_track_sound.state(), PlaybackState::Paused);
+
+	track.resume_all(Tween::default()).unwrap();
+	render(&mut manager);
+	assert_eq!(sub_track_sound.state(), PlaybackState::Playing);
+}
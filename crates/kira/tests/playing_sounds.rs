@@ -0,0 +1,56 @@
+use std::{mem::size_of, time::Duration};
+
+use kira::{
+	dsp::Frame,
+	manager::{backend::mock::MockBackend, AudioManager, AudioManagerSettings},
+	sound::{
+		static_sound::{StaticSoundData, StaticSoundSettings},
+		SoundInfo,
+	},
+	tween::Tween,
+};
+
+/// Tests that `AudioManager::playing_sounds` reports information about
+/// each playing sound in the order they were played and forgets sounds
+/// once they've been removed.
+#[test]
+fn reports_playing_sounds() {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings::default()).unwrap();
+	assert!(manager.playing_sounds().is_empty());
+
+	let mut first_handle = manager.play(sound_data(100)).unwrap();
+	manager.play(sound_data(50)).unwrap();
+	let playing_sounds = manager.playing_sounds();
+	let infos = playing_sounds
+		.iter()
+		.map(|(_, info)| *info)
+		.collect::<Vec<_>>();
+	assert_eq!(infos, vec![sound_info(100), sound_info(50)]);
+	assert_ne!(playing_sounds[0].0, playing_sounds[1].0);
+	// the order and ids should stay the same between calls
+	assert_eq!(manager.playing_sounds(), playing_sounds);
+
+	first_handle.stop(Tween::default()).unwrap();
+	let backend = manager.backend_mut();
+	for _ in 0..10 {
+		backend.on_start_processing();
+		backend.process();
+	}
+	assert_eq!(manager.playing_sounds(), vec![playing_sounds[1]]);
+}
+
+fn sound_data(num_frames: usize) -> StaticSoundData {
+	StaticSoundData::from_frames(
+		1,
+		vec![Frame::from_mono(1.0); num_frames],
+		StaticSoundSettings::new(),
+	)
+}
+
+fn sound_info(num_frames: usize) -> SoundInfo {
+	let mut info = SoundInfo::default();
+	info.duration = Some(Duration::from_secs(num_frames as u64));
+	info.sample_rate = Some(1);
+	info.memory_usage = num_frames * size_of::<Frame>();
+	info
+}